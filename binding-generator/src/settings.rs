@@ -572,8 +572,8 @@ pub static ELEMENT_IGNORE: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
 	"CvSeq", // 3.2 C struct
 	"FILE",
 	"HG_AUTOSIZE", // 3.2
-	"cv::ErrorCallback",
-	"cv::MatAllocator", // doesn't handle cpp part too well
+	"cv::ErrorCallback", // implemented manually in src/manual/core/error_callback.rs
+	"cv::MatAllocator", // doesn't handle cpp part too well, implemented manually in src/manual/core/mat_allocator.rs
 	"cv::NAryMatIterator", // uses pointers of pointers
 	"cv::Node", // template class
 	"std::exception_ptr",
@@ -783,6 +783,22 @@ pub static FORCE_CONSTANT_METHOD: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
 	"cv::UMat::step",
 });
 
+/// set of classes that are safe to share between threads, elements are Class.cpp_fullname()
+///
+/// `unsafe impl Sync` is generated for these classes in addition to `Send`. Only const C++ methods take `&self` in
+/// Rust, so `Sync` is sound when all const methods of the class can be called concurrently, i.e. they don't modify
+/// any internal state (caches, lazily initialized members, etc.) without synchronization. OpenCV gives no such
+/// guarantee in general, so each class must be checked before being added here. The class must also have no const
+/// methods that return objects sharing the data with the original (e.g. `Mat::row()` or `LDA::eigenvectors()`),
+/// those objects are writable, so `&self` would allow unsynchronized writes to the shared data. That's why `Mat` is
+/// not in the list.
+pub static CLASS_SYNC: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
+	"cv::PCA", // const methods are pure computations over the trained model
+	"cv::Range", // plain value
+	"cv::RotatedRect", // plain value
+	"cv::SVD", // const methods are pure computations over the decomposition
+});
+
 pub static PRIMITIVE_TYPEDEFS: Lazy<HashMap<&str, (&str, &str)>> = Lazy::new(|| hashmap! {
 	"size_t" => ("size_t", "size_t"),
 	"ptrdiff_t" => ("ptrdiff_t", "ptrdiff_t"),
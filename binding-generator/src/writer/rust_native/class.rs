@@ -14,6 +14,7 @@ use crate::{
 	get_debug,
 	IteratorExt,
 	NamePool,
+	settings,
 	StrExt,
 };
 
@@ -202,6 +203,11 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			.join("");
 
 		let rust_local = c.rust_localname();
+		let sync = if settings::CLASS_SYNC.contains(c.cpp_fullname().as_ref()) {
+			format!("unsafe impl Sync for {} {{}}\n", rust_local)
+		} else {
+			"".to_string()
+		};
 		let impls = if methods.iter().any(|m| m.is_clone()) {
 			IMPL_CLONE_TPL.interpolate(&hashmap! {
 				"rust_local" => rust_local.clone(),
@@ -217,6 +223,7 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			"rust_full" => c.rust_fullname(),
 			"rust_extern_const" => type_ref.rust_extern_with_const(ConstnessOverride::Yes(Constness::Const)),
 			"rust_extern_mut" => type_ref.rust_extern_with_const(ConstnessOverride::Yes(Constness::Mut)),
+			"sync" => sync.into(),
 			"fields" => fields.join("").into(),
 			"bases" => bases.join("").into(),
 			"impl" => IMPL_TPL.interpolate(&hashmap! {
@@ -19,7 +19,7 @@ impl {{rust_local}} {
 }
 
 unsafe impl Send for {{rust_local}} {}
-
+{{sync}}
 {{bases}}
 {{impl}}
 {{impls}}
@@ -12120,6 +12120,7 @@ impl PCA {
 }
 
 unsafe impl Send for PCA {}
+unsafe impl Sync for PCA {}
 
 impl core::PCATrait for PCA {
 	#[inline] fn as_raw_PCA(&self) -> *const c_void { self.as_raw() }
@@ -12691,6 +12692,7 @@ impl Range {
 }
 
 unsafe impl Send for Range {}
+unsafe impl Sync for Range {}
 
 impl core::RangeTrait for Range {
 	#[inline] fn as_raw_Range(&self) -> *const c_void { self.as_raw() }
@@ -12805,6 +12807,7 @@ impl RotatedRect {
 }
 
 unsafe impl Send for RotatedRect {}
+unsafe impl Sync for RotatedRect {}
 
 impl core::RotatedRectTrait for RotatedRect {
 	#[inline] fn as_raw_RotatedRect(&self) -> *const c_void { self.as_raw() }
@@ -12939,6 +12942,7 @@ impl SVD {
 }
 
 unsafe impl Send for SVD {}
+unsafe impl Sync for SVD {}
 
 impl core::SVDTrait for SVD {
 	#[inline] fn as_raw_SVD(&self) -> *const c_void { self.as_raw() }
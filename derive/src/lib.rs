@@ -0,0 +1,118 @@
+//! Derive macros for the [opencv](https://crates.io/crates/opencv) crate, use them through the `derive` feature of
+//! that crate, not directly
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+	Attribute,
+	Data,
+	DeriveInput,
+	Meta,
+	NestedMeta,
+	parse_macro_input,
+	spanned::Spanned,
+};
+
+/// Implement `opencv::core::DataType` for a struct so that it can be used as a `Mat` element
+///
+/// The struct must be `#[repr(C)]` or `#[repr(transparent)]` and `Copy`. All of its fields must have the same depth,
+/// they can be primitives (`u8`, `i8`, `u16`, `i16`, `i32`, `f32`, `f64`), `VecN`s of primitives or other structs
+/// deriving `DataType`. The number of channels is the total number of channels of the fields. Both conditions and the
+/// absence of padding between the fields are checked at compile time.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, DataType)]
+/// #[repr(C)]
+/// struct Bgr {
+///     b: u8,
+///     g: u8,
+///     r: u8,
+/// }
+///
+/// assert_eq!(core::CV_8UC3, Bgr::typ());
+/// ```
+#[proc_macro_derive(DataType)]
+pub fn derive_data_type(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	data_type(&input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+fn data_type(input: &DeriveInput) -> syn::Result<TokenStream2> {
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new_spanned(&input.generics, "DataType can't be derived for generic types"));
+	}
+	if !has_stable_layout(&input.attrs)? {
+		return Err(syn::Error::new_spanned(&input.ident, "DataType can only be derived for #[repr(C)] or #[repr(transparent)] structs"));
+	}
+	let fields = match &input.data {
+		Data::Struct(s) => &s.fields,
+		_ => return Err(syn::Error::new_spanned(&input.ident, "DataType can only be derived for structs")),
+	};
+	let first_field = fields.iter().next()
+		.ok_or_else(|| syn::Error::new_spanned(&input.ident, "DataType can't be derived for structs without fields"))?;
+
+	let ident = &input.ident;
+	let first_type = &first_field.ty;
+	let depth = quote! { <#first_type as ::opencv::core::DataTypeField>::Depth };
+	let channels = fields.iter().map(|field| {
+		let field_type = &field.ty;
+		quote! { <#field_type as ::opencv::core::DataTypeField>::CHANNELS }
+	});
+	let depth_checks = fields.iter().map(|field| {
+		let field_type = &field.ty;
+		quote_spanned! { field_type.span()=> same_depth::<#field_type, #depth>(); }
+	});
+
+	Ok(quote! {
+		unsafe impl ::opencv::core::DataTypeDerived for #ident {}
+
+		impl ::opencv::core::DataTypeField for #ident {
+			type Depth = #depth;
+			const CHANNELS: i32 = 0 #(+ #channels)*;
+		}
+
+		impl ::opencv::core::DataType for #ident {
+			#[inline(always)]
+			fn depth() -> i32 { <#depth as ::opencv::core::DataType>::depth() }
+
+			#[inline(always)]
+			fn channels() -> i32 { <Self as ::opencv::core::DataTypeField>::CHANNELS }
+
+			#[inline(always)]
+			fn typ() -> i32 { ::opencv::core::CV_MAKETYPE(Self::depth(), Self::channels()) }
+		}
+
+		#[allow(dead_code)]
+		const _: () = {
+			fn same_depth<T: ::opencv::core::DataTypeField<Depth = D>, D>() {}
+
+			fn check_depth() {
+				#(#depth_checks)*
+			}
+
+			// OpenCV expects the elements to be tightly packed
+			const _: [(); 1] = [(); (
+				::std::mem::size_of::<#ident>() == ::std::mem::size_of::<#depth>() * <#ident as ::opencv::core::DataTypeField>::CHANNELS as usize
+			) as usize];
+		};
+	})
+}
+
+/// Checks for `#[repr(C)]` or `#[repr(transparent)]`
+fn has_stable_layout(attrs: &[Attribute]) -> syn::Result<bool> {
+	for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+		if let Meta::List(list) = attr.parse_meta()? {
+			let stable = list.nested.iter().any(|nested| match nested {
+				NestedMeta::Meta(Meta::Path(path)) => path.is_ident("C") || path.is_ident("transparent"),
+				_ => false,
+			});
+			if stable {
+				return Ok(true);
+			}
+		}
+	}
+	Ok(false)
+}
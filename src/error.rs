@@ -5,15 +5,199 @@ use std::{
 
 use crate::core;
 
+// must match OCVRS_BAD_ALLOC_CODE, OCVRS_STD_EXCEPTION_CODE and OCVRS_UNKNOWN_EXCEPTION_CODE in src_cpp/ocvrs_common.hpp
+const BAD_ALLOC_CODE: i32 = -99997;
+const STD_EXCEPTION_CODE: i32 = -99998;
+const UNKNOWN_EXCEPTION_CODE: i32 = -99999;
+
+macro_rules! error_kind {
+	($($kind: ident),+ $(,)?) => {
+		/// Kind of the error, mirrors `cv::Error::Code`
+		#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+		pub enum ErrorKind {
+			$($kind,)+
+			/// C++ `std::bad_alloc`, e.g. from a failed `std::vector` or `std::string` allocation, the allocation
+			/// failures of the OpenCV allocator are reported as `StsNoMem`
+			OutOfMemory,
+			/// C++ exception derived from `std::exception` that has no better matching kind, check
+			/// `Error::exception_type()` for the actual type
+			StdException,
+			/// C++ exception of unknown type
+			Unknown,
+			/// Error code that is not a part of `cv::Error::Code`
+			Other(i32),
+		}
+
+		impl ErrorKind {
+			pub fn from_code(code: i32) -> Self {
+				match code {
+					$(core::$kind => ErrorKind::$kind,)+
+					BAD_ALLOC_CODE => ErrorKind::OutOfMemory,
+					STD_EXCEPTION_CODE => ErrorKind::StdException,
+					UNKNOWN_EXCEPTION_CODE => ErrorKind::Unknown,
+					code => ErrorKind::Other(code),
+				}
+			}
+
+			pub fn code(self) -> i32 {
+				match self {
+					$(ErrorKind::$kind => core::$kind,)+
+					ErrorKind::OutOfMemory => BAD_ALLOC_CODE,
+					ErrorKind::StdException => STD_EXCEPTION_CODE,
+					ErrorKind::Unknown => UNKNOWN_EXCEPTION_CODE,
+					ErrorKind::Other(code) => code,
+				}
+			}
+		}
+	};
+}
+
+error_kind!(
+	StsBackTrace,
+	StsError,
+	StsInternal,
+	StsNoMem,
+	StsBadArg,
+	StsBadFunc,
+	StsNoConv,
+	StsAutoTrace,
+	HeaderIsNull,
+	BadImageSize,
+	BadOffset,
+	BadDataPtr,
+	BadStep,
+	BadModelOrChSeq,
+	BadNumChannels,
+	BadNumChannel1U,
+	BadDepth,
+	BadAlphaChannel,
+	BadOrder,
+	BadOrigin,
+	BadAlign,
+	BadCallBack,
+	BadTileSize,
+	BadCOI,
+	BadROISize,
+	MaskIsTiled,
+	StsNullPtr,
+	StsVecLengthErr,
+	StsFilterStructContentErr,
+	StsKernelStructContentErr,
+	StsFilterOffsetErr,
+	StsBadSize,
+	StsDivByZero,
+	StsInplaceNotSupported,
+	StsObjectNotFound,
+	StsUnmatchedFormats,
+	StsBadFlag,
+	StsBadPoint,
+	StsBadMask,
+	StsUnmatchedSizes,
+	StsUnsupportedFormat,
+	StsOutOfRange,
+	StsParseError,
+	StsNotImplemented,
+	StsBadMemBlock,
+	StsAssert,
+	GpuNotSupported,
+	GpuApiCallError,
+	OpenGlNotSupported,
+	OpenGlApiCallError,
+	OpenCLApiCallError,
+	OpenCLDoubleNotSupported,
+	OpenCLInitError,
+	OpenCLNoAMDBlasFft,
+);
+
+impl From<i32> for ErrorKind {
+	#[inline]
+	fn from(code: i32) -> Self {
+		Self::from_code(code)
+	}
+}
+
+impl From<ErrorKind> for i32 {
+	#[inline]
+	fn from(kind: ErrorKind) -> Self {
+		kind.code()
+	}
+}
+
+/// Construct with `Error::new()`, the struct is non-exhaustive because of the private error details
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Error {
 	pub code: i32,
+	/// Full message, for `cv::Exception` it also includes the OpenCV version and the source location, for other C++
+	/// exceptions it's prefixed with the exception type
 	pub message: String,
+	/// Boxed to keep `Result` small, it's only present for the errors coming from the C++ side
+	pub(crate) details: Option<Box<ErrorDetails>>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ErrorDetails {
+	pub err: Option<String>,
+	pub func: Option<String>,
+	pub file: Option<String>,
+	pub line: Option<u32>,
+	pub exception_type: Option<String>,
+	pub wrapper: Option<String>,
 }
 
 impl Error {
 	pub fn new(code: i32, message: String) -> Self {
-		Self { code, message }
+		Self { code, message, details: None }
+	}
+
+	#[inline]
+	fn details(&self) -> Option<&ErrorDetails> {
+		self.details.as_deref()
+	}
+
+	#[inline]
+	pub fn kind(&self) -> ErrorKind {
+		ErrorKind::from_code(self.code)
+	}
+
+	/// Error description without the source location, for `cv::Exception` that's the `err` field, e.g. the failed
+	/// assertion condition, for other C++ exceptions it's the result of `what()`
+	#[inline]
+	pub fn bare_message(&self) -> &str {
+		self.details().and_then(|d| d.err.as_deref()).unwrap_or(&self.message)
+	}
+
+	/// OpenCV function that raised the error, if known
+	#[inline]
+	pub fn function(&self) -> Option<&str> {
+		self.details().and_then(|d| d.func.as_deref())
+	}
+
+	/// OpenCV source file where the error was raised, if known
+	#[inline]
+	pub fn file(&self) -> Option<&str> {
+		self.details().and_then(|d| d.file.as_deref())
+	}
+
+	/// Line in the OpenCV source file where the error was raised, if known
+	#[inline]
+	pub fn line(&self) -> Option<u32> {
+		self.details().and_then(|d| d.line)
+	}
+
+	/// Demangled name of the C++ exception type, e.g. `std::bad_alloc` or `cv::Exception`, `None` for the errors
+	/// originating on the Rust side
+	#[inline]
+	pub fn exception_type(&self) -> Option<&str> {
+		self.details().and_then(|d| d.exception_type.as_deref())
+	}
+
+	/// Name of the binding function that caught the error, e.g. `cv_imshow_const_StringR_const__InputArrayR`, it's
+	/// the same as the name of the function from `sys` module called by the Rust wrapper, `None` for the errors
+	/// originating on the Rust side
+	#[inline]
+	pub fn wrapper(&self) -> Option<&str> {
+		self.details().and_then(|d| d.wrapper.as_deref())
 	}
 }
 
@@ -1,6 +1,9 @@
 #![allow(broken_intra_doc_links)]
 
-pub use error::{Error, Result};
+#[cfg(all(feature = "half", not(feature = "opencv-4")))]
+compile_error!("`half` feature requires OpenCV 4 (`opencv-4` feature), `CV_16F` is not available in the earlier versions");
+
+pub use error::{Error, ErrorKind, Result};
 
 pub use crate::opencv::hub::*;
 
@@ -1,15 +1,17 @@
 pub use affine3::*;
+pub use error_callback::*;
+pub use file_node::*;
 pub use CV_MAKETYPE as CV_MAKE_TYPE;
 pub use gpumat::*;
 pub use input_output_array::*;
 pub use mat::*;
+pub use mat_allocator::*;
 pub use matx::*;
 pub use point::*;
 pub use point3::*;
 pub use ptr::*;
 pub use rect::*;
 pub use size::*;
-pub use sized::*;
 pub use vec::*;
 pub use vector::*;
 
@@ -31,7 +33,13 @@ macro_rules! valid_types {
 
 macro_rules! opencv_type_simple_generic {
 	($type: ident<$trait: ident>) => {
-		impl<T: $trait> $crate::traits::OpenCVType<'_> for $type<T> {
+		opencv_type_simple_generic! { @impl $type<T>, [T: $trait] }
+	};
+	($type: ident<$trait: ident, const $n: ident>) => {
+		opencv_type_simple_generic! { @impl $type<T, $n>, [T: $trait, const $n: usize] }
+	};
+	(@impl $type: ty, [$($generics: tt)*]) => {
+		impl<$($generics)*> $crate::traits::OpenCVType<'_> for $type {
 			type Arg = Self;
 			type ExternReceive = Self;
 			type ExternContainer = Self;
@@ -41,14 +49,14 @@ macro_rules! opencv_type_simple_generic {
 			#[inline] unsafe fn opencv_from_extern(s: Self) -> Self { s }
 		}
 
-		impl<T: $trait> $crate::traits::OpenCVTypeArg<'_> for $type<T> {
+		impl<$($generics)*> $crate::traits::OpenCVTypeArg<'_> for $type {
 			type ExternContainer = Self;
 
 			#[inline] fn opencv_into_extern_container(self) -> $crate::Result<Self> { Ok(self) }
 			#[inline] fn opencv_into_extern_container_nofail(self) -> Self { self }
 		}
 
-		impl<T: $trait> $crate::traits::OpenCVTypeExternContainer for $type<T> {
+		impl<$($generics)*> $crate::traits::OpenCVTypeExternContainer for $type {
 			type ExternSend = *const Self;
 			type ExternSendMut = *mut Self;
 
@@ -60,16 +68,25 @@ macro_rules! opencv_type_simple_generic {
 }
 
 mod affine3;
+mod error_callback;
+mod file_node;
+#[cfg(feature = "serde")]
+pub mod file_storage_serde;
 mod gpumat;
 mod input_output_array;
 mod mat;
+mod mat_allocator;
 mod matx;
+mod matx_ops;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
 mod point3;
 mod point;
 pub(crate) mod ptr;
 mod rect;
+#[cfg(feature = "serde")]
+mod serde_interop;
 mod size;
-mod sized;
 mod vec;
 mod vector;
 
@@ -0,0 +1,124 @@
+use std::{
+	cell::Cell,
+	convert::TryFrom,
+	ffi::{c_void, CStr},
+	mem,
+	os::raw::c_char,
+	panic::{self, AssertUnwindSafe},
+	ptr,
+	sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use once_cell::sync::Lazy;
+
+use crate::{
+	core,
+	Error,
+	ErrorKind,
+	Result,
+	sys,
+};
+
+/// Callback for `redirect_error()`, receives the details of every error raised by OpenCV
+pub type ErrorCallback = Option<Box<dyn FnMut(&ErrorInfo) + Send + 'static>>;
+
+/// Callback installed by `redirect_error()`, OpenCV only gets the forwarder that calls it, so the callback can be
+/// dropped when it's replaced while OpenCV may still be calling the forwarder from the other threads
+static ERROR_CALLBACK: Lazy<Mutex<ErrorCallback>> = Lazy::new(|| Mutex::new(None));
+
+/// Userdata for the trampoline, allocated once and reused by every installed callback
+static FORWARDER: Lazy<usize> = Lazy::new(|| {
+	let forwarder: Box<dyn FnMut(i32, *const c_char, *const c_char, *const c_char, i32) -> i32 + Send + Sync> = Box::new(forward_error);
+	Box::into_raw(Box::new(forwarder)) as usize
+});
+
+thread_local! {
+	/// Set while the callback is running on the current thread to skip the errors it raises itself instead of
+	/// deadlocking on `ERROR_CALLBACK`
+	static IN_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Details of the OpenCV error passed to the `ErrorCallback`, same as the fields of `cv::Exception`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorInfo {
+	pub code: i32,
+	/// Function where the error was raised
+	pub func: String,
+	/// Error description
+	pub err: String,
+	/// Source file where the error was raised
+	pub file: String,
+	/// Line in the source file where the error was raised, same as `Error::line()`
+	pub line: Option<u32>,
+}
+
+impl ErrorInfo {
+	unsafe fn from_raw(code: i32, func: *const c_char, err: *const c_char, file: *const c_char, line: i32) -> Self {
+		let string = |s: *const c_char| if s.is_null() {
+			String::new()
+		} else {
+			CStr::from_ptr(s).to_string_lossy().into_owned()
+		};
+		Self {
+			code,
+			func: string(func),
+			err: string(err),
+			file: string(file),
+			line: u32::try_from(line).ok().filter(|&line| line > 0),
+		}
+	}
+
+	#[inline]
+	pub fn kind(&self) -> ErrorKind {
+		ErrorKind::from_code(self.code)
+	}
+}
+
+/// Sets the new error handler that's called for every error raised by OpenCV before it's returned as `Err`, pass
+/// `None` to restore the default handler. Installing any callback also disables the default printing of the errors
+/// to stderr, see `silence_errors()` if that's the only goal.
+///
+/// The callback is called from the thread that raised the error, the calls from different threads are serialized by
+/// a mutex. The errors raised by OpenCV functions called from within the callback are not passed to it again, they
+/// are still returned as `Err` to the callback, and calling `redirect_error()` from the callback fails. Panics in the
+/// callback are caught and ignored. The replaced callback is dropped.
+pub fn redirect_error(callback: ErrorCallback) -> Result<()> {
+	extern "C" { fn cv_manual_redirectError(callback: Option<unsafe extern "C" fn(i32, *const c_char, *const c_char, *const c_char, i32, *mut c_void) -> i32>, userdata: *mut c_void) -> sys::Result<*mut c_void>; }
+	if IN_CALLBACK.with(Cell::get) {
+		return Err(Error::new(core::StsError, "Error callback can't be replaced from within the error callback".into()));
+	}
+	let on_error = callback.as_ref().map(|_| *FORWARDER);
+	// dropped after the lock is released in case its Drop raises OpenCV errors
+	let prev = mem::replace(&mut *lock_callback(), callback);
+	drop(prev);
+	callback_arg!(on_error_trampoline(code: i32, func: *const c_char, err: *const c_char, file: *const c_char, line: i32, userdata: *mut c_void) -> i32 => userdata in callbacks => on_error(code: i32, func: *const c_char, err: *const c_char, file: *const c_char, line: i32) -> i32);
+	let userdata = on_error.map_or(ptr::null_mut(), |forwarder| forwarder as *mut c_void);
+	unsafe { cv_manual_redirectError(on_error_trampoline, userdata) }
+		.into_result()
+		.map(|_| ())
+}
+
+#[inline]
+fn lock_callback() -> MutexGuard<'static, ErrorCallback> {
+	// panics are caught inside the callback, but don't propagate the poison if they escape anyway
+	ERROR_CALLBACK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn forward_error(code: i32, func: *const c_char, err: *const c_char, file: *const c_char, line: i32) -> i32 {
+	if IN_CALLBACK.with(|in_callback| in_callback.replace(true)) {
+		return 0;
+	}
+	if let Some(callback) = lock_callback().as_mut() {
+		let info = unsafe { ErrorInfo::from_raw(code, func, err, file, line) };
+		let _ = panic::catch_unwind(AssertUnwindSafe(|| callback(&info)));
+	}
+	IN_CALLBACK.with(|in_callback| in_callback.set(false));
+	0
+}
+
+/// Install the error handler that ignores all errors, this disables the default printing of the OpenCV errors to
+/// stderr while still returning them as `Err`
+#[inline]
+pub fn silence_errors() -> Result<()> {
+	redirect_error(Some(Box::new(|_| {})))
+}
@@ -0,0 +1,257 @@
+use std::{
+	convert::TryFrom,
+	ffi::c_void,
+};
+
+use crate::{
+	core::{self, DMatch, FileNode, FileNodeIterator, FileNodeIteratorTrait, FileNodeTrait, KeyPoint, Mat, Point2f},
+	Error,
+	Result,
+	sys,
+};
+
+mod private {
+	pub trait Sealed {}
+}
+
+/// Key of the mapping node (`&str`) or position of the element inside the sequence or mapping node (`usize`), used by
+/// `FileNodeTraitManual::child()`
+pub trait FileNodeIndex: private::Sealed {
+	fn child_of(self, node: &(impl FileNodeTrait + ?Sized)) -> Option<FileNode>;
+}
+
+impl private::Sealed for &str {}
+
+impl FileNodeIndex for &str {
+	fn child_of(self, node: &(impl FileNodeTrait + ?Sized)) -> Option<FileNode> {
+		if !node.is_map().ok()? {
+			return None;
+		}
+		node.get_node(self).ok()
+			.filter(|child| matches!(child.empty(), Ok(false)))
+	}
+}
+
+impl private::Sealed for usize {}
+
+impl FileNodeIndex for usize {
+	fn child_of(self, node: &(impl FileNodeTrait + ?Sized)) -> Option<FileNode> {
+		extern "C" { fn cv_manual_FileNode_child(instance: *const c_void, index: usize) -> sys::Result<*mut c_void>; }
+		if !(node.is_seq().ok()? || node.is_map().ok()?) || self >= node.size().ok()? {
+			return None;
+		}
+		unsafe { cv_manual_FileNode_child(node.as_raw_FileNode(), self) }
+			.into_result()
+			.map(|ptr| unsafe { FileNode::from_raw(ptr) })
+			.ok()
+	}
+}
+
+pub trait FileNodeTraitManual: FileNodeTrait {
+	/// Child node by the mapping key or by the position, `None` if there is no such node
+	///
+	/// ```no_run
+	/// # use opencv::prelude::*;
+	/// # fn f(node: &opencv::core::FileNode) -> Option<()> {
+	/// let width = node.child("cameras")?.child(0)?.child("width")?;
+	/// # Some(())
+	/// # }
+	/// ```
+	#[inline]
+	fn child<I: FileNodeIndex>(&self, index: I) -> Option<FileNode> {
+		index.child_of(self)
+	}
+
+	/// Iterate over the elements of the sequence or mapping node yielding `(name, node)` pairs, the names are empty for
+	/// the sequence elements
+	fn iter(&self) -> Result<FileNodeIter> {
+		let len = if self.is_seq()? || self.is_map()? {
+			self.size()?
+		} else {
+			0
+		};
+		Ok(FileNodeIter { iter: self.begin()?, remaining: len })
+	}
+}
+
+impl<T: FileNodeTrait> FileNodeTraitManual for T {}
+
+impl FileNode {
+	/// Extract the value of the node, e.g. `node.read::<f64>()` or `node.read::<Vec<String>>()`, fails if the node has
+	/// a different type or is missing
+	#[inline]
+	pub fn read<T: FromFileNode>(&self) -> Result<T> {
+		T::from_file_node(self)
+	}
+}
+
+/// Iterator over the elements of the sequence or mapping `FileNode`, created by `FileNodeTraitManual::iter()`
+pub struct FileNodeIter {
+	iter: FileNodeIterator,
+	remaining: usize,
+}
+
+impl Iterator for FileNodeIter {
+	type Item = (String, FileNode);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		extern "C" { fn cv_manual_FileNodeIterator_next(instance: *mut c_void) -> sys::Result<*mut c_void>; }
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let node = unsafe { cv_manual_FileNodeIterator_next(self.iter.as_raw_mut_FileNodeIterator()) }
+			.into_result()
+			.map(|ptr| unsafe { FileNode::from_raw(ptr) })
+			.ok()?;
+		Some((node.name().unwrap_or_default(), node))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl ExactSizeIterator for FileNodeIter {}
+
+impl IntoIterator for &FileNode {
+	type Item = (String, FileNode);
+	type IntoIter = FileNodeIter;
+
+	/// Panics if the iterator can't be created, use `iter()` to handle the error
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter().expect("Cannot iterate over FileNode")
+	}
+}
+
+impl IntoIterator for FileNode {
+	type Item = (String, FileNode);
+	type IntoIter = FileNodeIter;
+
+	/// Panics if the iterator can't be created, use `iter()` to handle the error
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter().expect("Cannot iterate over FileNode")
+	}
+}
+
+/// Types that can be extracted from `FileNode` with `FileNode::read()`
+pub trait FromFileNode: Sized {
+	fn from_file_node(node: &FileNode) -> Result<Self>;
+}
+
+/// Fails unless `is_type` returns true for the `node`, `expected` is the type name for the error message
+fn match_node_type(node: &FileNode, expected: &str, is_type: impl FnOnce(&FileNode) -> Result<bool>) -> Result<()> {
+	if node.empty()? {
+		Err(Error::new(core::StsObjectNotFound, format!("FileNode is missing, expected: {}", expected)))
+	} else if is_type(node)? {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsParseError, format!("FileNode of type: {} is not: {}", node.typ()?, expected)))
+	}
+}
+
+macro_rules! from_file_node_int {
+	($($type: ty),+ $(,)?) => {
+		$(
+			impl FromFileNode for $type {
+				fn from_file_node(node: &FileNode) -> Result<Self> {
+					match_node_type(node, "integer", |node| node.is_int())?;
+					let val = node.to_i32()?;
+					<$type>::try_from(val)
+						.map_err(|_| Error::new(core::StsOutOfRange, format!("Value: {} doesn't fit into {}", val, stringify!($type))))
+				}
+			}
+		)+
+	};
+}
+
+from_file_node_int!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+impl FromFileNode for bool {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		i32::from_file_node(node).map(|val| val != 0)
+	}
+}
+
+impl FromFileNode for f64 {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		match_node_type(node, "number", |node| Ok(node.is_real()? || node.is_int()?))?;
+		node.to_f64()
+	}
+}
+
+impl FromFileNode for f32 {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		f64::from_file_node(node).map(|val| val as f32)
+	}
+}
+
+impl FromFileNode for String {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		match_node_type(node, "string", |node| node.is_string())?;
+		node.to_string()
+	}
+}
+
+impl FromFileNode for Mat {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		match_node_type(node, "matrix", |node| node.is_map())?;
+		node.mat()
+	}
+}
+
+impl<T: FromFileNode> FromFileNode for Vec<T> {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		match_node_type(node, "sequence", |node| node.is_seq())?;
+		node.iter()?
+			.map(|(_, child)| T::from_file_node(&child))
+			.collect()
+	}
+}
+
+/// `None` if the node is missing
+impl<T: FromFileNode> FromFileNode for Option<T> {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		if node.empty()? {
+			Ok(None)
+		} else {
+			T::from_file_node(node).map(Some)
+		}
+	}
+}
+
+/// Reads the sequence of numbers in the order used by `cv::write()` for the compound types
+fn read_numbers<const N: usize>(node: &FileNode, expected: &str) -> Result<[f64; N]> {
+	let numbers = Vec::<f64>::from_file_node(node)?;
+	<[f64; N]>::try_from(numbers.as_slice())
+		.map_err(|_| Error::new(core::StsParseError, format!("{} must have {} elements, but it has: {}", expected, N, numbers.len())))
+}
+
+/// Stored as `[x, y, size, angle, response, octave, class_id]`
+impl FromFileNode for KeyPoint {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		let [x, y, size, angle, response, octave, class_id] = read_numbers(node, "KeyPoint")?;
+		Ok(KeyPoint {
+			pt: Point2f::new(x as f32, y as f32),
+			size: size as f32,
+			angle: angle as f32,
+			response: response as f32,
+			octave: octave as i32,
+			class_id: class_id as i32,
+		})
+	}
+}
+
+/// Stored as `[query_idx, train_idx, img_idx, distance]`
+impl FromFileNode for DMatch {
+	fn from_file_node(node: &FileNode) -> Result<Self> {
+		let [query_idx, train_idx, img_idx, distance] = read_numbers(node, "DMatch")?;
+		Ok(DMatch {
+			query_idx: query_idx as i32,
+			train_idx: train_idx as i32,
+			img_idx: img_idx as i32,
+			distance: distance as f32,
+		})
+	}
+}
@@ -0,0 +1,849 @@
+//! `serde` serializer and deserializer backed by `FileStorage`, enabled by the `serde` cargo feature
+//!
+//! Structs and maps are stored as OpenCV mappings, sequences and tuples as OpenCV sequences and `Mat` as a regular
+//! `opencv-matrix` node, so the files can be read by the C++ code using `cv::FileStorage` directly. Enums are stored
+//! as strings (unit variants) or single-key maps. `None` values are skipped, missing fields are deserialized as
+//! `None`. The top level value must be a struct or a map because that's what the OpenCV file root is.
+//!
+//! ```no_run
+//! # use opencv::core::file_storage_serde;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     threshold: f64,
+//!     name: String,
+//!     sizes: Vec<i32>,
+//! }
+//!
+//! # fn main() -> opencv::Result<()> {
+//! let config = Config { threshold: 0.5, name: "test".to_string(), sizes: vec![3, 5] };
+//! file_storage_serde::to_file("config.yml", &config)?;
+//! let config: Config = file_storage_serde::from_file("config.yml")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+	borrow::Cow,
+	convert::TryFrom,
+	ffi::{c_void, CString},
+	fmt,
+	mem,
+	os::raw::c_char,
+};
+
+use serde::{
+	de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor},
+	forward_to_deserialize_any,
+	ser::{self, Impossible, Serialize},
+};
+
+use crate::{
+	core::{self, FileNode, FileNodeIter, FileNodeTrait, FileNodeTraitManual, FileStorage, FileStorage_Mode, FileStorageTrait, Mat},
+	Error,
+	Result,
+	sys,
+};
+
+use super::mat::{Bytes, MatData};
+
+/// Name of the struct used by the `Serialize` and `Deserialize` implementations of `Mat`, a private name is used to
+/// not confuse `Mat` with the user structs named "Mat"
+const MAT_STRUCT: &str = "$__opencv_private_Mat";
+const MAT_FIELDS: [&str; 4] = ["rows", "cols", "type", "data"];
+
+impl ser::Error for Error {
+	fn custom<T: fmt::Display>(msg: T) -> Self {
+		Error::new(core::StsError, msg.to_string())
+	}
+}
+
+impl de::Error for Error {
+	fn custom<T: fmt::Display>(msg: T) -> Self {
+		Error::new(core::StsError, msg.to_string())
+	}
+}
+
+/// Serialize `value` into the already opened `fs`, the fields of the top level struct are written to the file root
+pub fn to_file_storage<T: Serialize + ?Sized>(fs: &mut FileStorage, value: &T) -> Result<()> {
+	value.serialize(&mut Serializer::new(fs))
+}
+
+/// Serialize `value` into the file `filename`, the format is determined by the extension (`.xml`, `.yml`, `.yaml` or
+/// `.json`, optionally followed by `.gz`)
+pub fn to_file<T: Serialize + ?Sized>(filename: &str, value: &T) -> Result<()> {
+	let mut fs = open(filename, FileStorage_Mode::WRITE as i32)?;
+	to_file_storage(&mut fs, value)?;
+	fs.release()
+}
+
+/// Serialize `value` into the string using in-memory `FileStorage`, `format` is the file extension that determines
+/// the output format, e.g. `.yml`
+pub fn to_string<T: Serialize + ?Sized>(value: &T, format: &str) -> Result<String> {
+	let mut fs = open(format, FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32)?;
+	to_file_storage(&mut fs, value)?;
+	fs.release_and_get_string()
+}
+
+/// Deserialize the value from the `node`, pass the file root to read the top level struct
+pub fn from_file_node<T: DeserializeOwned>(node: &FileNode) -> Result<T> {
+	T::deserialize(Deserializer::new(FileNode::copy(node)?))
+}
+
+/// Deserialize the value from the root of the file `filename`
+pub fn from_file<T: DeserializeOwned>(filename: &str) -> Result<T> {
+	let fs = open(filename, FileStorage_Mode::READ as i32)?;
+	from_file_node(&fs.root(0)?)
+}
+
+/// Deserialize the value from the string `s` containing XML, YAML or JSON data written by OpenCV
+pub fn from_str<T: DeserializeOwned>(s: &str) -> Result<T> {
+	let fs = open(s, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32)?;
+	from_file_node(&fs.root(0)?)
+}
+
+fn open(source: &str, flags: i32) -> Result<FileStorage> {
+	let fs = FileStorage::new(source, flags, "")?;
+	if fs.is_opened()? {
+		Ok(fs)
+	} else {
+		let source = if flags & FileStorage_Mode::MEMORY as i32 != 0 { "memory" } else { source };
+		Err(Error::new(core::StsError, format!("Can't open FileStorage: {}", source)))
+	}
+}
+
+fn unsupported(what: &str) -> Error {
+	Error::new(core::StsNotImplemented, format!("FileStorage doesn't support {}", what))
+}
+
+/// `serde::Serializer` writing into `FileStorage`, use `to_file_storage()`, `to_file()` or `to_string()` to create one
+pub struct Serializer<'f> {
+	fs: &'f mut FileStorage,
+	/// Key of the next value when writing into a mapping, empty inside sequences
+	name: String,
+	/// Number of open mappings and sequences including the implicit root mapping
+	depth: usize,
+}
+
+impl<'f> Serializer<'f> {
+	pub fn new(fs: &'f mut FileStorage) -> Self {
+		Self { fs, name: String::new(), depth: 0 }
+	}
+
+	/// Name for the next scalar value, fails at the top level
+	fn scalar_name(&mut self) -> Result<CString> {
+		if self.depth == 0 {
+			return Err(Error::new(core::StsBadArg, "Top level value must be a struct or a map".to_string()));
+		}
+		Ok(CString::new(mem::take(&mut self.name))?)
+	}
+
+	fn write_int(&mut self, val: i32) -> Result<()> {
+		extern "C" { fn cv_manual_FileStorage_write_int(instance: *mut c_void, name: *const c_char, val: i32) -> sys::Result_void; }
+		let name = self.scalar_name()?;
+		unsafe { cv_manual_FileStorage_write_int(self.fs.as_raw_mut_FileStorage(), name.as_ptr(), val) }.into_result()
+	}
+
+	fn write_int_from<T: Copy + fmt::Display>(&mut self, val: T) -> Result<()> where i32: TryFrom<T> {
+		let int = i32::try_from(val)
+			.map_err(|_| Error::new(core::StsOutOfRange, format!("Value: {} doesn't fit into FileStorage integer", val)))?;
+		self.write_int(int)
+	}
+
+	fn write_double(&mut self, val: f64) -> Result<()> {
+		extern "C" { fn cv_manual_FileStorage_write_double(instance: *mut c_void, name: *const c_char, val: f64) -> sys::Result_void; }
+		let name = self.scalar_name()?;
+		unsafe { cv_manual_FileStorage_write_double(self.fs.as_raw_mut_FileStorage(), name.as_ptr(), val) }.into_result()
+	}
+
+	fn write_string(&mut self, val: &str) -> Result<()> {
+		extern "C" { fn cv_manual_FileStorage_write_string(instance: *mut c_void, name: *const c_char, val: *const c_char) -> sys::Result_void; }
+		let name = self.scalar_name()?;
+		let val = CString::new(val)?;
+		unsafe { cv_manual_FileStorage_write_string(self.fs.as_raw_mut_FileStorage(), name.as_ptr(), val.as_ptr()) }.into_result()
+	}
+
+	fn write_mat(&mut self, val: &Mat) -> Result<()> {
+		extern "C" { fn cv_manual_FileStorage_write_mat(instance: *mut c_void, name: *const c_char, val: *const c_void) -> sys::Result_void; }
+		let name = self.scalar_name()?;
+		unsafe { cv_manual_FileStorage_write_mat(self.fs.as_raw_mut_FileStorage(), name.as_ptr(), val.as_raw_Mat()) }.into_result()
+	}
+
+	/// Open a new mapping or sequence, the top level mapping is the file root so it's not written explicitly
+	fn start(&mut self, is_seq: bool) -> Result<Level> {
+		extern "C" { fn cv_manual_FileStorage_start_struct(instance: *mut c_void, name: *const c_char, is_seq: bool) -> sys::Result_void; }
+		if self.depth == 0 {
+			if is_seq {
+				return Err(Error::new(core::StsBadArg, "Top level value must be a struct or a map".to_string()));
+			}
+			self.depth += 1;
+			return Ok(Level::Root);
+		}
+		let name = CString::new(mem::take(&mut self.name))?;
+		unsafe { cv_manual_FileStorage_start_struct(self.fs.as_raw_mut_FileStorage(), name.as_ptr(), is_seq) }.into_result()?;
+		self.depth += 1;
+		Ok(if is_seq { Level::Seq } else { Level::Map })
+	}
+
+	fn end(&mut self, level: Level) -> Result<()> {
+		extern "C" { fn cv_manual_FileStorage_end_struct(instance: *mut c_void, is_seq: bool) -> sys::Result_void; }
+		self.depth -= 1;
+		match level {
+			Level::Root => Ok(()),
+			Level::Map | Level::Seq => unsafe { cv_manual_FileStorage_end_struct(self.fs.as_raw_mut_FileStorage(), level == Level::Seq) }.into_result(),
+		}
+	}
+
+	/// Enum variants with data are written as a single-key mapping `{ variant: data }`
+	fn start_variant(&mut self, variant: &str, is_seq: bool) -> Result<Compound<'_, 'f>> {
+		let outer = self.start(false)?;
+		self.name = variant.to_string();
+		let inner = self.start(is_seq)?;
+		Ok(Compound { ser: self, ends: [Some(inner), Some(outer)], mat: None })
+	}
+}
+
+/// Structure opened by `Serializer::start()`
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Level {
+	/// Implicit mapping at the file root
+	Root,
+	Map,
+	Seq,
+}
+
+impl<'a, 'f> ser::Serializer for &'a mut Serializer<'f> {
+	type Ok = ();
+	type Error = Error;
+	type SerializeSeq = Compound<'a, 'f>;
+	type SerializeTuple = Compound<'a, 'f>;
+	type SerializeTupleStruct = Compound<'a, 'f>;
+	type SerializeTupleVariant = Compound<'a, 'f>;
+	type SerializeMap = Compound<'a, 'f>;
+	type SerializeStruct = Compound<'a, 'f>;
+	type SerializeStructVariant = Compound<'a, 'f>;
+
+	fn serialize_bool(self, v: bool) -> Result<()> {
+		self.write_int(v as i32)
+	}
+
+	fn serialize_i8(self, v: i8) -> Result<()> {
+		self.write_int(v.into())
+	}
+
+	fn serialize_i16(self, v: i16) -> Result<()> {
+		self.write_int(v.into())
+	}
+
+	fn serialize_i32(self, v: i32) -> Result<()> {
+		self.write_int(v)
+	}
+
+	fn serialize_i64(self, v: i64) -> Result<()> {
+		self.write_int_from(v)
+	}
+
+	fn serialize_u8(self, v: u8) -> Result<()> {
+		self.write_int(v.into())
+	}
+
+	fn serialize_u16(self, v: u16) -> Result<()> {
+		self.write_int(v.into())
+	}
+
+	fn serialize_u32(self, v: u32) -> Result<()> {
+		self.write_int_from(v)
+	}
+
+	fn serialize_u64(self, v: u64) -> Result<()> {
+		self.write_int_from(v)
+	}
+
+	fn serialize_f32(self, v: f32) -> Result<()> {
+		self.write_double(v.into())
+	}
+
+	fn serialize_f64(self, v: f64) -> Result<()> {
+		self.write_double(v)
+	}
+
+	fn serialize_char(self, v: char) -> Result<()> {
+		self.write_string(v.encode_utf8(&mut [0; 4]))
+	}
+
+	fn serialize_str(self, v: &str) -> Result<()> {
+		self.write_string(v)
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+		let ends = [Some(self.start(true)?), None];
+		for &x in v {
+			self.write_int(x.into())?;
+		}
+		Compound { ser: self, ends, mat: None }.finish()
+	}
+
+	fn serialize_none(self) -> Result<()> {
+		self.serialize_unit()
+	}
+
+	fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+		value.serialize(self)
+	}
+
+	/// Mapping entries with unit values are skipped, there is no representation for them in `FileStorage`
+	fn serialize_unit(self) -> Result<()> {
+		if self.depth > 0 && !self.name.is_empty() {
+			self.name.clear();
+			Ok(())
+		} else {
+			Err(unsupported("empty values inside sequences"))
+		}
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+		self.serialize_unit()
+	}
+
+	fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<()> {
+		self.write_string(variant)
+	}
+
+	fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<()> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _variant_index: u32, variant: &'static str, value: &T) -> Result<()> {
+		let outer = self.start(false)?;
+		self.name = variant.to_string();
+		value.serialize(&mut *self)?;
+		Compound { ser: self, ends: [None, Some(outer)], mat: None }.finish()
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+		let ends = [Some(self.start(true)?), None];
+		Ok(Compound { ser: self, ends, mat: None })
+	}
+
+	fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct> {
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant> {
+		self.start_variant(variant, true)
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+		let ends = [Some(self.start(false)?), None];
+		Ok(Compound { ser: self, ends, mat: None })
+	}
+
+	fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+		if name == MAT_STRUCT {
+			// the fields are collected and written as a single matrix node at the end
+			Ok(Compound { ser: self, ends: [None, None], mat: Some(MatFields::default()) })
+		} else {
+			self.serialize_map(Some(len))
+		}
+	}
+
+	fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant> {
+		self.start_variant(variant, false)
+	}
+}
+
+/// Fields of `Mat` collected during serialization
+#[derive(Default)]
+struct MatFields {
+	rows: i32,
+	cols: i32,
+	typ: i32,
+	data: Vec<u8>,
+}
+
+/// State of the mapping or sequence being serialized
+pub struct Compound<'a, 'f> {
+	ser: &'a mut Serializer<'f>,
+	/// Structures to close at the end, innermost first
+	ends: [Option<Level>; 2],
+	mat: Option<MatFields>,
+}
+
+impl Compound<'_, '_> {
+	fn finish(self) -> Result<()> {
+		if let Some(mat) = self.mat {
+			let mat = MatData { rows: mat.rows, cols: mat.cols, typ: mat.typ, data: Bytes(Cow::Owned(mat.data)) }.into_mat()?;
+			return self.ser.write_mat(&mat);
+		}
+		for &level in self.ends.iter().flatten() {
+			self.ser.end(level)?;
+		}
+		Ok(())
+	}
+
+	fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		value.serialize(&mut *self.ser)
+	}
+
+	fn field<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<()> {
+		if let Some(mat) = &mut self.mat {
+			let captured = value.serialize(MatFieldSerializer)?;
+			match (key, captured) {
+				("rows", MatField::Int(v)) => mat.rows = v,
+				("cols", MatField::Int(v)) => mat.cols = v,
+				("type", MatField::Int(v)) => mat.typ = v,
+				("data", MatField::Bytes(v)) => mat.data = v,
+				_ => return Err(Error::new(core::StsBadArg, format!("Unexpected Mat field: {}", key))),
+			}
+			Ok(())
+		} else {
+			self.ser.name = key.to_string();
+			value.serialize(&mut *self.ser)
+		}
+	}
+}
+
+impl ser::SerializeSeq for Compound<'_, '_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		self.element(value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTuple for Compound<'_, '_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		self.element(value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTupleStruct for Compound<'_, '_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		self.element(value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeTupleVariant for Compound<'_, '_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		self.element(value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeMap for Compound<'_, '_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+		self.ser.name = key.serialize(KeySerializer)?;
+		Ok(())
+	}
+
+	fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		value.serialize(&mut *self.ser)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeStruct for Compound<'_, '_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<()> {
+		self.field(key, value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+impl ser::SerializeStructVariant for Compound<'_, '_> {
+	type Ok = ();
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<()> {
+		self.field(key, value)
+	}
+
+	fn end(self) -> Result<()> {
+		self.finish()
+	}
+}
+
+/// Implements the `serde::Serializer` methods that are not supported by the restricted serializers
+macro_rules! serialize_unsupported {
+	($what: literal; $($method: ident($($arg: ty),*) -> $ret: ty),+ $(,)?) => {
+		$(
+			fn $method(self, $(_: $arg),*) -> Result<$ret> {
+				Err(unsupported($what))
+			}
+		)+
+	};
+}
+
+/// Serializes the mapping keys, OpenCV only supports strings
+struct KeySerializer;
+
+impl ser::Serializer for KeySerializer {
+	type Ok = String;
+	type Error = Error;
+	type SerializeSeq = Impossible<String, Error>;
+	type SerializeTuple = Impossible<String, Error>;
+	type SerializeTupleStruct = Impossible<String, Error>;
+	type SerializeTupleVariant = Impossible<String, Error>;
+	type SerializeMap = Impossible<String, Error>;
+	type SerializeStruct = Impossible<String, Error>;
+	type SerializeStructVariant = Impossible<String, Error>;
+
+	fn serialize_str(self, v: &str) -> Result<String> {
+		Ok(v.to_string())
+	}
+
+	fn serialize_char(self, v: char) -> Result<String> {
+		Ok(v.to_string())
+	}
+
+	fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<String> {
+		Ok(variant.to_string())
+	}
+
+	fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<String> {
+		value.serialize(self)
+	}
+
+	fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<String> {
+		Err(unsupported("non-string mapping keys"))
+	}
+
+	fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> Result<String> {
+		Err(unsupported("non-string mapping keys"))
+	}
+
+	serialize_unsupported! {
+		"non-string mapping keys";
+		serialize_bool(bool) -> String,
+		serialize_i8(i8) -> String,
+		serialize_i16(i16) -> String,
+		serialize_i32(i32) -> String,
+		serialize_i64(i64) -> String,
+		serialize_u8(u8) -> String,
+		serialize_u16(u16) -> String,
+		serialize_u32(u32) -> String,
+		serialize_u64(u64) -> String,
+		serialize_f32(f32) -> String,
+		serialize_f64(f64) -> String,
+		serialize_bytes(&[u8]) -> String,
+		serialize_none() -> String,
+		serialize_unit() -> String,
+		serialize_unit_struct(&'static str) -> String,
+		serialize_seq(Option<usize>) -> Self::SerializeSeq,
+		serialize_tuple(usize) -> Self::SerializeTuple,
+		serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+		serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant,
+		serialize_map(Option<usize>) -> Self::SerializeMap,
+		serialize_struct(&'static str, usize) -> Self::SerializeStruct,
+		serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant,
+	}
+}
+
+enum MatField {
+	Int(i32),
+	Bytes(Vec<u8>),
+}
+
+/// Captures the values of the `Mat` fields
+struct MatFieldSerializer;
+
+impl ser::Serializer for MatFieldSerializer {
+	type Ok = MatField;
+	type Error = Error;
+	type SerializeSeq = Impossible<MatField, Error>;
+	type SerializeTuple = Impossible<MatField, Error>;
+	type SerializeTupleStruct = Impossible<MatField, Error>;
+	type SerializeTupleVariant = Impossible<MatField, Error>;
+	type SerializeMap = Impossible<MatField, Error>;
+	type SerializeStruct = Impossible<MatField, Error>;
+	type SerializeStructVariant = Impossible<MatField, Error>;
+
+	fn serialize_i32(self, v: i32) -> Result<MatField> {
+		Ok(MatField::Int(v))
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<MatField> {
+		Ok(MatField::Bytes(v.to_vec()))
+	}
+
+	fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<MatField> {
+		Err(unsupported("this Mat field"))
+	}
+
+	fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, _value: &T) -> Result<MatField> {
+		Err(unsupported("this Mat field"))
+	}
+
+	fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> Result<MatField> {
+		Err(unsupported("this Mat field"))
+	}
+
+	serialize_unsupported! {
+		"this Mat field";
+		serialize_bool(bool) -> MatField,
+		serialize_i8(i8) -> MatField,
+		serialize_i16(i16) -> MatField,
+		serialize_i64(i64) -> MatField,
+		serialize_u8(u8) -> MatField,
+		serialize_u16(u16) -> MatField,
+		serialize_u32(u32) -> MatField,
+		serialize_u64(u64) -> MatField,
+		serialize_f32(f32) -> MatField,
+		serialize_f64(f64) -> MatField,
+		serialize_char(char) -> MatField,
+		serialize_str(&str) -> MatField,
+		serialize_none() -> MatField,
+		serialize_unit() -> MatField,
+		serialize_unit_struct(&'static str) -> MatField,
+		serialize_unit_variant(&'static str, u32, &'static str) -> MatField,
+		serialize_seq(Option<usize>) -> Self::SerializeSeq,
+		serialize_tuple(usize) -> Self::SerializeTuple,
+		serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+		serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant,
+		serialize_map(Option<usize>) -> Self::SerializeMap,
+		serialize_struct(&'static str, usize) -> Self::SerializeStruct,
+		serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant,
+	}
+}
+
+/// `serde::Deserializer` reading from `FileNode`, use `from_file_node()`, `from_file()` or `from_str()` to create one
+pub struct Deserializer {
+	node: FileNode,
+}
+
+impl Deserializer {
+	pub fn new(node: FileNode) -> Self {
+		Self { node }
+	}
+
+	fn is_empty(&self) -> Result<bool> {
+		Ok(self.node.empty()? || self.node.is_none()?)
+	}
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+	type Error = Error;
+
+	fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		let node = &self.node;
+		if self.is_empty()? {
+			visitor.visit_unit()
+		} else if node.is_int()? {
+			visitor.visit_i32(node.to_i32()?)
+		} else if node.is_real()? {
+			visitor.visit_f64(node.to_f64()?)
+		} else if node.is_string()? {
+			visitor.visit_string(node.to_string()?)
+		} else if node.is_seq()? {
+			visitor.visit_seq(NodeAccess::new(node)?)
+		} else if node.is_map()? {
+			visitor.visit_map(NodeAccess::new(node)?)
+		} else {
+			Err(Error::new(core::StsParseError, format!("Unsupported FileNode type: {}", node.typ()?)))
+		}
+	}
+
+	fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		if self.node.is_int()? {
+			visitor.visit_bool(self.node.to_i32()? != 0)
+		} else {
+			self.deserialize_any(visitor)
+		}
+	}
+
+	fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		if self.is_empty()? {
+			visitor.visit_none()
+		} else {
+			visitor.visit_some(self)
+		}
+	}
+
+	fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_struct<V: Visitor<'de>>(self, name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+		if name == MAT_STRUCT && fields == MAT_FIELDS && self.node.is_map()? {
+			let mat = self.node.mat()?;
+			let data = MatData::new(&mat)?;
+			visitor.visit_map(MatAccess { data, index: 0 })
+		} else {
+			self.deserialize_any(visitor)
+		}
+	}
+
+	fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value> {
+		if self.node.is_string()? {
+			visitor.visit_enum(self.node.to_string()?.into_deserializer())
+		} else if self.node.is_map()? && self.node.size()? == 1 {
+			let (variant, value) = self.node.iter()?.next()
+				.ok_or_else(|| Error::new(core::StsParseError, "Can't read the enum variant".to_string()))?;
+			visitor.visit_enum(VariantAccess { variant, value })
+		} else {
+			Err(Error::new(core::StsParseError, "Enum must be stored as a string or a single-key mapping".to_string()))
+		}
+	}
+
+	fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		visitor.visit_unit()
+	}
+
+	forward_to_deserialize_any! {
+		i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+		bytes byte_buf unit unit_struct seq tuple tuple_struct map identifier
+	}
+}
+
+/// Access to the children of the sequence or mapping node
+struct NodeAccess {
+	iter: FileNodeIter,
+	/// Mapping value whose key was already returned by `next_key_seed()`
+	value: Option<FileNode>,
+}
+
+impl NodeAccess {
+	fn new(node: &FileNode) -> Result<Self> {
+		Ok(Self { iter: node.iter()?, value: None })
+	}
+}
+
+impl<'de> de::SeqAccess<'de> for NodeAccess {
+	type Error = Error;
+
+	fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+		self.iter.next()
+			.map(|(_, node)| seed.deserialize(Deserializer::new(node)))
+			.transpose()
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.iter.len())
+	}
+}
+
+impl<'de> de::MapAccess<'de> for NodeAccess {
+	type Error = Error;
+
+	fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+		if let Some((name, node)) = self.iter.next() {
+			let name: de::value::StringDeserializer<Error> = name.into_deserializer();
+			self.value = Some(node);
+			seed.deserialize(name).map(Some)
+		} else {
+			Ok(None)
+		}
+	}
+
+	fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+		let node = self.value.take()
+			.ok_or_else(|| Error::new(core::StsError, "Mapping value requested before its key".to_string()))?;
+		seed.deserialize(Deserializer::new(node))
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.iter.len())
+	}
+}
+
+/// Presents the matrix node read with `FileNode::mat()` as the fields of `Mat` serialized representation
+struct MatAccess<'m> {
+	data: MatData<'m>,
+	index: usize,
+}
+
+impl<'de> de::MapAccess<'de> for MatAccess<'_> {
+	type Error = Error;
+
+	fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+		MAT_FIELDS.get(self.index)
+			.map(|&field| seed.deserialize(field.into_deserializer()))
+			.transpose()
+	}
+
+	fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+		self.index += 1;
+		match self.index {
+			1 => seed.deserialize(self.data.rows.into_deserializer()),
+			2 => seed.deserialize(self.data.cols.into_deserializer()),
+			3 => seed.deserialize(self.data.typ.into_deserializer()),
+			_ => seed.deserialize(de::value::BytesDeserializer::new(&self.data.data.0)),
+		}
+	}
+}
+
+/// Enum variant stored as a single-key mapping
+struct VariantAccess {
+	variant: String,
+	value: FileNode,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantAccess {
+	type Error = Error;
+	type Variant = Deserializer;
+
+	fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+		let variant: de::value::StringDeserializer<Error> = self.variant.into_deserializer();
+		let variant = seed.deserialize(variant)?;
+		Ok((variant, Deserializer::new(self.value)))
+	}
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer {
+	type Error = Error;
+
+	fn unit_variant(self) -> Result<()> {
+		Ok(())
+	}
+
+	fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+		seed.deserialize(self)
+	}
+
+	fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+		de::Deserializer::deserialize_seq(self, visitor)
+	}
+
+	fn struct_variant<V: Visitor<'de>>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+		de::Deserializer::deserialize_map(self, visitor)
+	}
+}
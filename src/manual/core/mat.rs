@@ -1,12 +1,34 @@
 use std::{
-	convert::TryInto,
+	convert::{TryFrom, TryInto},
 	ffi::c_void,
 	fmt,
+	mem,
 	ops::Deref,
+	ptr,
 	slice,
 };
 
+#[cfg(feature = "derive")]
+pub use opencv_derive::DataType;
+pub use borrowed_mat::{BorrowedMat, BorrowedMatMut};
 pub use mat_::*;
+pub use mapped_mat::{MappedMat, MappedMatMut};
+pub use mat_display::MatDisplay;
+use mat_display::FormatType;
+#[cfg(feature = "image")]
+pub use mat_image::{ChannelOrder, MatPixel};
+pub use mat_iter::{MatIter, MatIterMut, MatRowIter, MatRowIterMut};
+pub use mat_ops::MatExprResult;
+#[cfg(feature = "rayon")]
+pub use mat_par::{MatParChunksMut, MatParRowsMut};
+#[cfg(feature = "ndarray")]
+use mat_ndarray::{array_view, array_view_mut};
+pub use mat_ref::*;
+#[cfg(feature = "serde")]
+pub(crate) use mat_serde::{Bytes, MatData};
+pub use mat_slice::SliceArg;
+use mat_slice::slice_ranges;
+pub use sparse_mat_::*;
 
 use crate::{
 	core::{
@@ -14,15 +36,19 @@ use crate::{
 		_InputArray,
 		_InputOutputArray,
 		_OutputArray,
+		MatAllocatorHandle,
 		MatExpr,
 		MatSize,
 		MatStep,
 		Point,
+		Range,
+		Rect,
 		Scalar,
 		ToInputArray,
 		ToInputOutputArray,
 		ToOutputArray,
 		UMat,
+		Vector,
 	},
 	Error,
 	platform_types::size_t,
@@ -31,15 +57,300 @@ use crate::{
 	sys,
 };
 
+/// Read-only accessors for the `Mat` views that don't implement `MatTrait`, forwards to the `inner` field
+///
+/// The views that must not hand out `&Mat` (because any `&Mat` can produce the headers sharing the data) expose only
+/// this restricted set of methods that return the data borrowed from the view.
+macro_rules! mat_view_forward {
+	() => {
+		#[inline]
+		pub fn rows(&self) -> i32 {
+			self.inner.rows()
+		}
+
+		#[inline]
+		pub fn cols(&self) -> i32 {
+			self.inner.cols()
+		}
+
+		#[inline]
+		pub fn dims(&self) -> i32 {
+			self.inner.dims()
+		}
+
+		#[inline]
+		pub fn size(&self) -> $crate::Result<$crate::core::Size> {
+			self.inner.size()
+		}
+
+		/// Size along each dimension
+		#[inline]
+		pub fn shape(&self) -> &[i32] {
+			self.inner.shape()
+		}
+
+		#[inline]
+		pub fn typ(&self) -> $crate::Result<i32> {
+			self.inner.typ()
+		}
+
+		#[inline]
+		pub fn depth(&self) -> $crate::Result<i32> {
+			self.inner.depth()
+		}
+
+		#[inline]
+		pub fn channels(&self) -> $crate::Result<i32> {
+			self.inner.channels()
+		}
+
+		#[inline]
+		pub fn elem_size(&self) -> $crate::Result<usize> {
+			self.inner.elem_size()
+		}
+
+		#[inline]
+		pub fn total(&self) -> $crate::Result<usize> {
+			self.inner.total()
+		}
+
+		#[inline]
+		pub fn empty(&self) -> $crate::Result<bool> {
+			self.inner.empty()
+		}
+
+		#[inline]
+		pub fn is_continuous(&self) -> $crate::Result<bool> {
+			self.inner.is_continuous()
+		}
+
+		#[inline]
+		pub fn is_submatrix(&self) -> $crate::Result<bool> {
+			self.inner.is_submatrix()
+		}
+
+		/// First byte of the data
+		#[inline]
+		pub fn data(&self) -> $crate::Result<&u8> {
+			self.inner.data()
+		}
+
+		#[inline]
+		pub fn data_typed<T: $crate::core::DataType>(&self) -> $crate::Result<&[T]> {
+			self.inner.data_typed()
+		}
+
+		#[inline]
+		pub fn at<T: $crate::core::DataType>(&self, i0: i32) -> $crate::Result<&T> {
+			self.inner.at(i0)
+		}
+
+		#[inline]
+		pub fn at_2d<T: $crate::core::DataType>(&self, row: i32, col: i32) -> $crate::Result<&T> {
+			self.inner.at_2d(row, col)
+		}
+
+		#[inline]
+		pub fn at_pt<T: $crate::core::DataType>(&self, pt: $crate::core::Point) -> $crate::Result<&T> {
+			self.inner.at_pt(pt)
+		}
+
+		#[inline]
+		pub fn at_3d<T: $crate::core::DataType>(&self, i0: i32, i1: i32, i2: i32) -> $crate::Result<&T> {
+			self.inner.at_3d(i0, i1, i2)
+		}
+
+		#[inline]
+		pub fn at_nd<T: $crate::core::DataType>(&self, idx: &[i32]) -> $crate::Result<&T> {
+			self.inner.at_nd(idx)
+		}
+
+		/// Return a complete read-only row
+		#[inline]
+		pub fn at_row<T: $crate::core::DataType>(&self, row: i32) -> $crate::Result<&[T]> {
+			self.inner.at_row(row)
+		}
+
+		#[inline]
+		pub fn iter<T: $crate::core::DataType>(&self) -> $crate::Result<$crate::core::MatIter<T>> {
+			self.inner.iter()
+		}
+
+		#[inline]
+		pub fn iter_rows<T: $crate::core::DataType>(&self) -> $crate::Result<$crate::core::MatRowIter<T>> {
+			self.inner.iter_rows()
+		}
+
+		#[inline]
+		pub fn to_vec_2d<T: $crate::core::DataType>(&self) -> $crate::Result<Vec<Vec<T>>> {
+			self.inner.to_vec_2d()
+		}
+
+		#[cfg(feature = "ndarray")]
+		#[inline]
+		pub fn as_array_view<T: $crate::core::DataType>(&self) -> $crate::Result<ndarray::ArrayView2<T>> {
+			self.inner.as_array_view()
+		}
+
+		#[cfg(feature = "ndarray")]
+		#[inline]
+		pub fn as_array_view_channels<T: $crate::core::DataType>(&self) -> $crate::Result<ndarray::ArrayView3<T>> {
+			self.inner.as_array_view_channels()
+		}
+
+		#[cfg(feature = "ndarray")]
+		#[inline]
+		pub fn as_array_view_nd<T: $crate::core::DataType>(&self) -> $crate::Result<ndarray::ArrayViewD<T>> {
+			self.inner.as_array_view_nd()
+		}
+
+		#[cfg(feature = "image")]
+		#[inline]
+		pub fn to_image<P: $crate::core::MatPixel>(&self, order: $crate::core::ChannelOrder) -> $crate::Result<image::ImageBuffer<P, Vec<P::Subpixel>>> {
+			self.inner.to_image(order)
+		}
+
+		#[cfg(feature = "image")]
+		#[inline]
+		pub fn as_image<P: $crate::core::MatPixel>(&self) -> $crate::Result<image::ImageBuffer<P, &[P::Subpixel]>> {
+			self.inner.as_image()
+		}
+
+		/// Copy the data of the view into a new independent `Mat`
+		#[inline]
+		pub fn to_mat(&self) -> $crate::Result<$crate::core::Mat> {
+			self.inner.try_clone()
+		}
+	};
+}
+
+/// Mutable counterparts of the `mat_view_forward!` accessors
+macro_rules! mat_view_forward_mut {
+	() => {
+		#[inline]
+		pub fn data_typed_mut<T: $crate::core::DataType>(&mut self) -> $crate::Result<&mut [T]> {
+			self.inner.data_typed_mut()
+		}
+
+		#[inline]
+		pub fn at_mut<T: $crate::core::DataType>(&mut self, i0: i32) -> $crate::Result<&mut T> {
+			self.inner.at_mut(i0)
+		}
+
+		#[inline]
+		pub fn at_2d_mut<T: $crate::core::DataType>(&mut self, row: i32, col: i32) -> $crate::Result<&mut T> {
+			self.inner.at_2d_mut(row, col)
+		}
+
+		#[inline]
+		pub fn at_pt_mut<T: $crate::core::DataType>(&mut self, pt: $crate::core::Point) -> $crate::Result<&mut T> {
+			self.inner.at_pt_mut(pt)
+		}
+
+		#[inline]
+		pub fn at_3d_mut<T: $crate::core::DataType>(&mut self, i0: i32, i1: i32, i2: i32) -> $crate::Result<&mut T> {
+			self.inner.at_3d_mut(i0, i1, i2)
+		}
+
+		#[inline]
+		pub fn at_nd_mut<T: $crate::core::DataType>(&mut self, idx: &[i32]) -> $crate::Result<&mut T> {
+			self.inner.at_nd_mut(idx)
+		}
+
+		/// Return a complete writeable row
+		#[inline]
+		pub fn at_row_mut<T: $crate::core::DataType>(&mut self, row: i32) -> $crate::Result<&mut [T]> {
+			self.inner.at_row_mut(row)
+		}
+
+		#[inline]
+		pub fn iter_mut<T: $crate::core::DataType>(&mut self) -> $crate::Result<$crate::core::MatIterMut<T>> {
+			self.inner.iter_mut()
+		}
+
+		#[inline]
+		pub fn iter_rows_mut<T: $crate::core::DataType>(&mut self) -> $crate::Result<$crate::core::MatRowIterMut<T>> {
+			self.inner.iter_rows_mut()
+		}
+
+		/// Sets all the elements to the specified value
+		#[inline]
+		pub fn set(&mut self, s: $crate::core::Scalar) -> $crate::Result<()> {
+			self.inner.set(s)
+		}
+
+		#[cfg(feature = "ndarray")]
+		#[inline]
+		pub fn as_array_view_mut<T: $crate::core::DataType>(&mut self) -> $crate::Result<ndarray::ArrayViewMut2<T>> {
+			self.inner.as_array_view_mut()
+		}
+
+		#[cfg(feature = "ndarray")]
+		#[inline]
+		pub fn as_array_view_channels_mut<T: $crate::core::DataType>(&mut self) -> $crate::Result<ndarray::ArrayViewMut3<T>> {
+			self.inner.as_array_view_channels_mut()
+		}
+
+		#[cfg(feature = "ndarray")]
+		#[inline]
+		pub fn as_array_view_nd_mut<T: $crate::core::DataType>(&mut self) -> $crate::Result<ndarray::ArrayViewMutD<T>> {
+			self.inner.as_array_view_nd_mut()
+		}
+
+		#[cfg(feature = "image")]
+		#[inline]
+		pub fn as_image_mut<P: $crate::core::MatPixel>(&mut self) -> $crate::Result<image::ImageBuffer<P, &mut [P::Subpixel]>> {
+			self.inner.as_image_mut()
+		}
+	};
+}
+
+mod borrowed_mat;
+mod mapped_mat;
 mod mat_;
+mod mat_display;
+#[cfg(feature = "image")]
+mod mat_image;
+mod mat_iter;
+mod mat_ops;
+#[cfg(feature = "ndarray")]
+mod mat_ndarray;
+#[cfg(feature = "rayon")]
+mod mat_par;
+mod mat_ref;
+#[cfg(feature = "serde")]
+mod mat_serde;
+mod mat_slice;
+mod sparse_mat_;
 
 /// This sealed trait is implemented for types that are valid to use as Mat elements
+///
+/// With the `derive` feature enabled it can also be implemented for your own structs using `#[derive(DataType)]`.
 pub trait DataType: Copy + private::Sealed {
 	fn depth() -> i32;
 	fn channels() -> i32;
 	fn typ() -> i32;
 }
 
+/// Depth and number of channels of the `DataType` known at compile time
+///
+/// Used by `#[derive(DataType)]` to check that all fields of the struct have the same depth.
+#[doc(hidden)]
+pub trait DataTypeField: DataType {
+	type Depth: DataType;
+	const CHANNELS: i32;
+}
+
+/// Marker for the structs implementing `DataType` through `#[derive(DataType)]`, it unlocks the sealed `DataType`
+///
+/// # Safety
+/// The type must consist only of the tightly packed elements of the same depth with the layout that OpenCV expects,
+/// the derive macro checks that at compile time. Don't implement it manually.
+#[doc(hidden)]
+pub unsafe trait DataTypeDerived: DataTypeField {}
+
+impl<T: DataTypeDerived> private::Sealed for T {}
+
 macro_rules! data_type {
 	($rust_type: ty, $mat_depth: expr, $channels: expr) => {
 		impl $crate::core::DataType for $rust_type {
@@ -67,6 +378,8 @@ data_type!(i32, core::CV_32S, 1);
 // float
 data_type!(f32, core::CV_32F, 1);
 data_type!(f64, core::CV_64F, 1);
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+data_type!(half::f16, core::CV_16F, 1);
 
 // vec int
 data_type!(core::Vec2b, core::CV_8U, 2);
@@ -93,6 +406,12 @@ data_type!(core::Vec4f, core::CV_32F, 4);
 data_type!(core::Vec2d, core::CV_64F, 2);
 data_type!(core::Vec3d, core::CV_64F, 3);
 data_type!(core::Vec4d, core::CV_64F, 4);
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+data_type!(core::Vec2<half::f16>, core::CV_16F, 2);
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+data_type!(core::Vec3<half::f16>, core::CV_16F, 3);
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+data_type!(core::Vec4<half::f16>, core::CV_16F, 4);
 
 // scalar
 data_type!(core::Scalar, core::CV_64F, 4);
@@ -117,6 +436,26 @@ data_type!(core::Rect2i, core::CV_32S, 4);
 data_type!(core::Rect2f, core::CV_32F, 4);
 data_type!(core::Rect2d, core::CV_64F, 4);
 
+macro_rules! data_type_field {
+	($($rust_type: ty),+) => {
+		$(
+			impl DataTypeField for $rust_type {
+				type Depth = Self;
+				const CHANNELS: i32 = 1;
+			}
+		)+
+	};
+}
+
+data_type_field!(u8, i8, u16, i16, i32, f32, f64);
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+data_type_field!(half::f16);
+
+impl<T: DataTypeField + core::ValidVecType, const N: usize> DataTypeField for core::VecN<T, N> where Self: DataType {
+	type Depth = T::Depth;
+	const CHANNELS: i32 = T::CHANNELS * N as i32;
+}
+
 
 #[inline(always)]
 unsafe fn convert_ptr<T>(r: &u8) -> &T {
@@ -150,6 +489,15 @@ fn match_dims(mat: &(impl MatTrait + ?Sized), dims: usize) -> Result<()> {
 	}
 }
 
+/// Like `match_dims(mat, 2)`, but also accepts the default constructed `Mat` which has 0 dims
+fn match_dims_2d(mat: &(impl MatTrait + ?Sized)) -> Result<()> {
+	if mat.dims() == 0 {
+		Ok(())
+	} else {
+		match_dims(mat, 2)
+	}
+}
+
 fn match_indices(mat: &(impl MatTrait + ?Sized), idx: &[i32]) -> Result<()> {
 	let size = mat.mat_size();
 	match_dims(mat, idx.len())?;
@@ -180,6 +528,55 @@ fn match_is_continuous(mat: &(impl MatTrait + ?Sized)) -> Result<()> {
 	}
 }
 
+fn match_split(idx: i32, len: i32, dim_name: &str) -> Result<()> {
+	if 0 <= idx && idx <= len {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsOutOfRange, format!("Split index: {} along dimension: {} out of bounds 0..={}", idx, dim_name, len)))
+	}
+}
+
+/// Validates that the slice of `len` elements of type `T` can hold `rows` x `cols` matrix with the specified
+/// row `step` in bytes, returns the actual step to use
+pub(super) fn match_slice_layout<T: DataType>(rows: i32, cols: i32, len: usize, step: usize) -> Result<usize> {
+	if rows < 0 || cols < 0 {
+		return Err(Error::new(core::StsBadArg, format!("Invalid matrix dimensions: {}x{}", rows, cols)));
+	}
+	let elem_size = mem::size_of::<T>();
+	let row_size = cols as usize * elem_size;
+	let step = if step == core::Mat_AUTO_STEP {
+		row_size
+	} else {
+		step
+	};
+	if step < row_size || step % elem_size != 0 {
+		return Err(Error::new(core::StsBadArg, format!("Invalid step: {}, it must be a multiple of the element size: {} and not less than the row size: {}", step, elem_size, row_size)));
+	}
+	let required_size = if rows == 0 || cols == 0 {
+		0
+	} else {
+		(rows as usize - 1) * step + row_size
+	};
+	let available_size = len * elem_size;
+	if required_size > available_size {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Slice of {} bytes is too small for {}x{} matrix with step: {}, required: {} bytes", available_size, rows, cols, step, required_size)));
+	}
+	Ok(step)
+}
+
+/// Converts the slice length to the number of `Mat` columns, fails if it doesn't fit
+fn slice_cols(len: usize) -> Result<i32> {
+	i32::try_from(len)
+		.map_err(|_| Error::new(core::StsOutOfRange, format!("Slice of {} elements is too long for a Mat row, maximum is: {}", len, i32::MAX)))
+}
+
+#[inline]
+unsafe fn rowscols_unbound(mat: &(impl MatTrait + ?Sized), row_range: &Range, col_range: &Range) -> Result<Mat> {
+	sys::cv_Mat_Mat_const_MatR_const_RangeR_const_RangeR(mat.as_raw_Mat(), row_range.as_raw_Range(), col_range.as_raw_Range())
+		.into_result()
+		.map(|ptr| Mat::from_raw(ptr))
+}
+
 #[inline(always)]
 fn idx_to_row_col(mat: &(impl MatTrait + ?Sized), i0: i32) -> Result<(i32, i32)> {
 	Ok(if mat.is_continuous()? {
@@ -231,6 +628,41 @@ impl Mat {
 		Ok(out)
 	}
 
+	/// Create a single-row read-only `Mat` view of the slice data without copying it
+	#[inline]
+	pub fn from_slice_ref<T: DataType>(s: &[T]) -> Result<BorrowedMat> {
+		Self::new_rows_cols_with_slice(1, slice_cols(s.len())?, s, core::Mat_AUTO_STEP)
+	}
+
+	/// Create a single-row mutable `Mat` view of the slice data without copying it
+	#[inline]
+	pub fn from_slice_mut<T: DataType>(s: &mut [T]) -> Result<BorrowedMatMut> {
+		Self::new_rows_cols_with_slice_mut(1, slice_cols(s.len())?, s, core::Mat_AUTO_STEP)
+	}
+
+	/// Create a read-only `Mat` view of the slice data without copying it
+	///
+	/// ## Parameters
+	/// * rows: Number of rows in a 2D array.
+	/// * cols: Number of columns in a 2D array.
+	/// * s: Slice with the matrix data, it stays borrowed while the returned view is alive.
+	/// * step: Number of bytes each matrix row occupies, including the padding bytes at the end of the row. Pass
+	///    `Mat_AUTO_STEP` if there is no padding.
+	pub fn new_rows_cols_with_slice<T: DataType>(rows: i32, cols: i32, s: &[T], step: usize) -> Result<BorrowedMat> {
+		let step = match_slice_layout::<T>(rows, cols, s.len(), step)?;
+		unsafe { Self::new_rows_cols_with_data(rows, cols, T::typ(), s.as_ptr() as *mut c_void, step) }
+			.map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+
+	/// Create a mutable `Mat` view of the slice data without copying it
+	///
+	/// See `new_rows_cols_with_slice()` for the description of the parameters.
+	pub fn new_rows_cols_with_slice_mut<T: DataType>(rows: i32, cols: i32, s: &mut [T], step: usize) -> Result<BorrowedMatMut> {
+		let step = match_slice_layout::<T>(rows, cols, s.len(), step)?;
+		unsafe { Self::new_rows_cols_with_data(rows, cols, T::typ(), s.as_mut_ptr() as *mut c_void, step) }
+			.map(|mat| unsafe { BorrowedMatMut::new(mat) })
+	}
+
 	pub fn try_into_typed<T: DataType>(self) -> Result<Mat_<T>> where Self: Sized {
 		self.try_into()
 	}
@@ -430,12 +862,213 @@ pub trait MatTraitManual: MatTrait {
 			.map(|x| slice::from_raw_parts_mut(convert_ptr_mut(x), width))
 	}
 
+	/// Iterate over the elements of 2D `Mat` yielding their positions and values
+	///
+	/// Works for non-continuous `Mat`s (e.g. ROIs) too, the type is checked only once during the iterator
+	/// creation and the data is then accessed directly without FFI calls.
+	fn iter<T: DataType>(&self) -> Result<MatIter<T>> {
+		MatIter::new(self)
+	}
+
+	/// Like `iter()`, but yields mutable references to the elements
+	fn iter_mut<T: DataType>(&mut self) -> Result<MatIterMut<T>> {
+		MatIterMut::new(self)
+	}
+
+	/// Iterate over the rows of 2D `Mat` as slices, works for non-continuous `Mat`s (e.g. ROIs) too
+	///
+	/// Named `iter_rows()` and not `rows()` because `MatTrait::rows()` already returns the number of rows.
+	fn iter_rows<T: DataType>(&self) -> Result<MatRowIter<T>> {
+		MatRowIter::new(self)
+	}
+
+	/// Like `iter_rows()`, but yields mutable slices
+	fn iter_rows_mut<T: DataType>(&mut self) -> Result<MatRowIterMut<T>> {
+		MatRowIterMut::new(self)
+	}
+
+	/// Parallel iterator over the rows of 2D `Mat` as mutable slices, each row can be processed on a separate
+	/// `rayon` thread
+	#[cfg(feature = "rayon")]
+	fn par_rows_mut<T: DataType + Send>(&mut self) -> Result<MatParRowsMut<T>> {
+		MatParRowsMut::new(self)
+	}
+
+	/// Parallel iterator over the non-overlapping bands of `rows_per_chunk` rows, each band can be processed on
+	/// a separate `rayon` thread
+	#[cfg(feature = "rayon")]
+	fn par_chunks_mut<T: DataType + Send>(&mut self, rows_per_chunk: i32) -> Result<MatParChunksMut<T>> {
+		MatParChunksMut::new(self, rows_per_chunk)
+	}
+
+	/// Zero-copy `ndarray` view into the data of 2D `Mat` with the shape of `(rows, cols)`, `T` must match the `Mat` type
+	///
+	/// Works for non-continuous `Mat`s (e.g. ROIs) too, use `to_owned()` on the view to get an owned array.
+	#[cfg(feature = "ndarray")]
+	fn as_array_view<T: DataType>(&self) -> Result<ndarray::ArrayView2<T>> {
+		array_view(self, Some(2), false)
+	}
+
+	/// Like `as_array_view()`, but returns a mutable view
+	#[cfg(feature = "ndarray")]
+	fn as_array_view_mut<T: DataType>(&mut self) -> Result<ndarray::ArrayViewMut2<T>> {
+		array_view_mut(self, Some(2), false)
+	}
+
+	/// Zero-copy `ndarray` view into the data of 2D `Mat` with the shape of `(rows, cols, channels)`, `T` must be
+	/// a single-channel type matching the `Mat` depth (e.g. `u8` for `CV_8UC3`)
+	#[cfg(feature = "ndarray")]
+	fn as_array_view_channels<T: DataType>(&self) -> Result<ndarray::ArrayView3<T>> {
+		array_view(self, Some(2), true)
+	}
+
+	/// Like `as_array_view_channels()`, but returns a mutable view
+	#[cfg(feature = "ndarray")]
+	fn as_array_view_channels_mut<T: DataType>(&mut self) -> Result<ndarray::ArrayViewMut3<T>> {
+		array_view_mut(self, Some(2), true)
+	}
+
+	/// Zero-copy `ndarray` view into the data of `Mat` with any number of dimensions (e.g. `dnn` blobs), the shape is
+	/// taken from `MatSize`, `T` must match the `Mat` type
+	#[cfg(feature = "ndarray")]
+	fn as_array_view_nd<T: DataType>(&self) -> Result<ndarray::ArrayViewD<T>> {
+		array_view(self, None, false)
+	}
+
+	/// Like `as_array_view_nd()`, but returns a mutable view
+	#[cfg(feature = "ndarray")]
+	fn as_array_view_nd_mut<T: DataType>(&mut self) -> Result<ndarray::ArrayViewMutD<T>> {
+		array_view_mut(self, None, false)
+	}
+
+	/// Copy the data of 2D `Mat` into a new `image::ImageBuffer`, `order` specifies the channel order of the `Mat` data
+	///
+	/// Works for non-continuous `Mat`s (e.g. ROIs) too, the element type of the `Mat` must match the pixel type `P`.
+	#[cfg(feature = "image")]
+	fn to_image<P: MatPixel>(&self, order: ChannelOrder) -> Result<image::ImageBuffer<P, Vec<P::Subpixel>>> {
+		mat_image::to_image(self, order)
+	}
+
+	/// Zero-copy `image::ImageBuffer` view into the data of continuous 2D `Mat`, the channels keep the order of
+	/// the `Mat` data
+	#[cfg(feature = "image")]
+	fn as_image<P: MatPixel>(&self) -> Result<image::ImageBuffer<P, &[P::Subpixel]>> {
+		mat_image::as_image(self)
+	}
+
+	/// Like `as_image()`, but returns a mutable view
+	#[cfg(feature = "image")]
+	fn as_image_mut<P: MatPixel>(&mut self) -> Result<image::ImageBuffer<P, &mut [P::Subpixel]>> {
+		mat_image::as_image_mut(self)
+	}
+
+	/// Return a read-only view of the `roi` region, the `Mat` stays borrowed while the view is alive
+	fn roi_ref(&self, roi: Rect) -> Result<MatRef> {
+		unsafe { sys::cv_Mat_Mat_const_MatR_const_RectR(self.as_raw_Mat(), &roi) }
+			.into_result()
+			.map(|ptr| unsafe { MatRef::new(Mat::from_raw(ptr)) })
+	}
+
+	/// Return a mutable view of the `roi` region, the `Mat` stays mutably borrowed while the view is alive
+	fn roi_mut(&mut self, roi: Rect) -> Result<MatRefMut> {
+		unsafe { sys::cv_Mat_Mat_const_MatR_const_RectR(self.as_raw_Mat(), &roi) }
+			.into_result()
+			.map(|ptr| unsafe { MatRefMut::new(Mat::from_raw(ptr)) })
+	}
+
+	/// Return a read-only view of the specified rows and columns, use `Range::all()` to take all of them
+	fn rowscols_ref(&self, row_range: &Range, col_range: &Range) -> Result<MatRef> {
+		unsafe { rowscols_unbound(self, row_range, col_range) }
+			.map(|mat| unsafe { MatRef::new(mat) })
+	}
+
+	/// Return a mutable view of the specified rows and columns, use `Range::all()` to take all of them
+	fn rowscols_mut(&mut self, row_range: &Range, col_range: &Range) -> Result<MatRefMut> {
+		unsafe { rowscols_unbound(self, row_range, col_range) }
+			.map(|mat| unsafe { MatRefMut::new(mat) })
+	}
+
+	/// Return a read-only view of the region selected by `ranges` along each dimension
+	fn ranges_ref(&self, ranges: &Vector<Range>) -> Result<MatRef> {
+		unsafe { sys::cv_Mat_Mat_const_MatR_const_vector_Range_R(self.as_raw_Mat(), ranges.as_raw_VectorOfRange()) }
+			.into_result()
+			.map(|ptr| unsafe { MatRef::new(Mat::from_raw(ptr)) })
+	}
+
+	/// Return a mutable view of the region selected by `ranges` along each dimension
+	fn ranges_mut(&mut self, ranges: &Vector<Range>) -> Result<MatRefMut> {
+		unsafe { sys::cv_Mat_Mat_const_MatR_const_vector_Range_R(self.as_raw_Mat(), ranges.as_raw_VectorOfRange()) }
+			.into_result()
+			.map(|ptr| unsafe { MatRefMut::new(Mat::from_raw(ptr)) })
+	}
+
+	/// Return a read-only view of the region selected by `s!` macro along each dimension, e.g.
+	/// `mat.slice(&s![.., 2..5, 0])`
+	fn slice(&self, args: &[SliceArg]) -> Result<MatRef> {
+		self.ranges_ref(&slice_ranges(self, args)?)
+	}
+
+	/// Like `slice()`, but returns a mutable view
+	fn slice_mut(&mut self, args: &[SliceArg]) -> Result<MatRefMut> {
+		let ranges = slice_ranges(self, args)?;
+		self.ranges_mut(&ranges)
+	}
+
+	/// Size along each dimension, same as `mat_size()`, but borrowed from the `Mat`
+	fn shape(&self) -> &[i32] {
+		let size = self.mat_size();
+		// MatSize points into the Mat header so the data stays valid while the Mat is borrowed
+		unsafe { slice::from_raw_parts(size.as_ptr(), size.len()) }
+	}
+
+	/// Return a read-only view of the data with the new `shape` and the same number of channels, the number of
+	/// elements must stay the same
+	fn reshape_ref(&self, shape: &[i32]) -> Result<MatRef> {
+		self.reshape_nd(0, shape)
+			.map(|mat| unsafe { MatRef::new(mat) })
+	}
+
+	/// Like `reshape_ref()`, but returns a mutable view
+	fn reshape_mut(&mut self, shape: &[i32]) -> Result<MatRefMut> {
+		self.reshape_nd(0, shape)
+			.map(|mat| unsafe { MatRefMut::new(mat) })
+	}
+
+	/// Split the 2D `Mat` into 2 non-overlapping mutable views, the first one contains rows `0..row` and the
+	/// second one contains rows `row..rows()`
+	fn split_at_row_mut(&mut self, row: i32) -> Result<(MatRefMut, MatRefMut)> {
+		match_dims(self, 2)
+			.and_then(|_| match_split(row, self.rows(), "rows"))?;
+		let all = Range::all()?;
+		let top = unsafe { rowscols_unbound(self, &Range::new(0, row)?, &all) }?;
+		let bottom = unsafe { rowscols_unbound(self, &Range::new(row, self.rows())?, &all) }?;
+		Ok(unsafe { (MatRefMut::new(top), MatRefMut::new(bottom)) })
+	}
+
+	/// Split the 2D `Mat` into 2 non-overlapping mutable views, the first one contains columns `0..col` and
+	/// the second one contains columns `col..cols()`
+	fn split_at_col_mut(&mut self, col: i32) -> Result<(MatRefMut, MatRefMut)> {
+		match_dims(self, 2)
+			.and_then(|_| match_split(col, self.cols(), "cols"))?;
+		let all = Range::all()?;
+		let left = unsafe { rowscols_unbound(self, &all, &Range::new(0, col)?) }?;
+		let right = unsafe { rowscols_unbound(self, &all, &Range::new(col, self.cols())?) }?;
+		Ok(unsafe { (MatRefMut::new(left), MatRefMut::new(right)) })
+	}
+
 	fn size(&self) -> Result<core::Size> {
 		extern "C" { fn cv_manual_Mat_size(instance: *const c_void) -> sys::Result<core::Size>; }
 		unsafe { cv_manual_Mat_size(self.as_raw_Mat()) }
 			.into_result()
 	}
 
+	/// Set the allocator that will be used for the subsequent (re)allocations of this `Mat` data, pass `None`
+	/// to use the default allocator
+	fn set_allocator(&mut self, allocator: Option<MatAllocatorHandle>) {
+		extern "C" { fn cv_manual_Mat_set_allocator(instance: *mut c_void, allocator: *mut c_void); }
+		unsafe { cv_manual_Mat_set_allocator(self.as_raw_mut_Mat(), allocator.map_or(ptr::null_mut(), |a| a.as_raw())) }
+	}
+
 	fn is_allocated(&self) -> bool {
 		extern "C" { fn cv_manual_Mat_is_allocated(instance: *const c_void) -> bool; }
 		unsafe { cv_manual_Mat_is_allocated(self.as_raw_Mat()) }
@@ -484,6 +1117,15 @@ pub trait MatTraitManual: MatTrait {
 		Ok(slice::from_raw_parts_mut(self.data_mut() as *mut _ as *mut _, total))
 	}
 
+	/// Adapter for displaying the values of the matrix in the specified style, see `MatDisplay` for details
+	///
+	/// The style is `core::Formatter_FormatType` with OpenCV 4 and one of the `core::Formatter_FMT_*` constants with
+	/// OpenCV 3
+	#[inline]
+	fn display_as(&self, fmt: FormatType) -> MatDisplay<Self> {
+		MatDisplay::new(self, fmt)
+	}
+
 	fn to_vec_2d<T: DataType>(&self) -> Result<Vec<Vec<T>>> {
 		match_format::<T>(self.typ()?)
 			.and_then(|_| match_dims(self, 2))
@@ -594,10 +1236,37 @@ pub trait UMatTraitManual: UMatTrait {
 		unsafe { cv_manual_UMat_size(self.as_raw_UMat()) }
 			.into_result()
 	}
+
+	/// Map the data to the host memory for reading, the mapping is released when the returned guard is dropped
+	#[inline]
+	fn map_read(&self) -> Result<MappedMat> {
+		MappedMat::new(self)
+	}
+
+	/// Map the data to the host memory for reading and writing, the mapping is released when the returned guard is
+	/// dropped
+	#[inline]
+	fn map_write(&mut self) -> Result<MappedMatMut> {
+		MappedMatMut::new(self)
+	}
+
+	/// Copy the data into a new independent `Mat`
+	fn to_mat(&self) -> Result<Mat> {
+		self.map_read()?.to_mat()
+	}
 }
 
 impl<T: UMatTrait> UMatTraitManual for T {}
 
+impl UMat {
+	/// Create a new `UMat` with a copy of the `mat` data
+	pub fn from_mat(mat: &Mat, usage_flags: core::UMatUsageFlags) -> Result<Self> {
+		let mut out = Self::new(usage_flags)?;
+		mat.copy_to(&mut out)?;
+		Ok(out)
+	}
+}
+
 impl ToInputArray for UMat {
 	#[inline]
 	fn input_array(&self) -> Result<_InputArray> {
@@ -0,0 +1,163 @@
+use std::{
+	fmt,
+	marker::PhantomData,
+};
+
+use crate::{
+	core::{
+		_InputArray,
+		_InputOutputArray,
+		_OutputArray,
+		Mat,
+		Rect,
+		ToInputArray,
+		ToInputOutputArray,
+		ToOutputArray,
+	},
+	prelude::*,
+	Result,
+	sys,
+	traits::Boxed,
+};
+
+/// Creates the ROI header that shares the data with `mat`
+///
+/// # Safety
+/// The result points into the `mat` data without holding a reference to it, caller must bind it to the lifetime
+/// of the data
+unsafe fn roi_unbound(mat: &Mat, roi: Rect) -> Result<Mat> {
+	sys::cv_Mat_Mat_const_MatR_const_RectR(mat.as_raw_Mat(), &roi)
+		.into_result()
+		.map(|ptr| Mat::from_raw(ptr))
+}
+
+/// Read-only view of a borrowed slice as a `Mat`, created by `Mat::from_slice_ref()` and similar functions
+///
+/// Unlike `MatRef` the data is not owned by OpenCV, so nothing would keep it alive in the `Mat` headers derived
+/// from the view. That's why the view doesn't expose the underlying `Mat` and only provides the methods that return
+/// borrowed data. It can still be passed as an input array to any OpenCV function, use `to_mat()` to get an
+/// independent copy.
+pub struct BorrowedMat<'s> {
+	inner: Mat,
+	_d: PhantomData<&'s [u8]>,
+}
+
+impl<'s> BorrowedMat<'s> {
+	/// # Safety
+	/// Caller must ensure that the data referenced by `inner` stays valid and is not mutated for the lifetime `'s`
+	#[inline]
+	pub(crate) unsafe fn new(inner: Mat) -> Self {
+		Self { inner, _d: PhantomData }
+	}
+
+	mat_view_forward!();
+
+	/// Read-only view of the `roi` region of the same data
+	pub fn roi(&self, roi: Rect) -> Result<BorrowedMat<'s>> {
+		unsafe { roi_unbound(&self.inner, roi) }
+			.map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+}
+
+impl ToInputArray for BorrowedMat<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl ToInputArray for &BorrowedMat<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl fmt::Debug for BorrowedMat<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
+
+/// Mutable view of a borrowed slice as a `Mat`, created by `Mat::from_slice_mut()` and similar functions
+///
+/// See `BorrowedMat` for the reasons of the restricted API. It can be passed as an output array to OpenCV functions,
+/// but the result is written into the slice only if it has the same size and type, otherwise OpenCV reallocates the
+/// data and the slice stays untouched.
+pub struct BorrowedMatMut<'s> {
+	inner: Mat,
+	_d: PhantomData<&'s mut [u8]>,
+}
+
+impl<'s> BorrowedMatMut<'s> {
+	/// # Safety
+	/// Caller must ensure that the data referenced by `inner` stays valid and is not aliased for the lifetime `'s`
+	#[inline]
+	pub(crate) unsafe fn new(inner: Mat) -> Self {
+		Self { inner, _d: PhantomData }
+	}
+
+	mat_view_forward!();
+
+	mat_view_forward_mut!();
+
+	/// Read-only view of the `roi` region of the same data, the view stays borrowed while the result is alive
+	pub fn roi(&self, roi: Rect) -> Result<BorrowedMat> {
+		unsafe { roi_unbound(&self.inner, roi) }
+			.map(|mat| unsafe { BorrowedMat::new(mat) })
+	}
+
+	/// Mutable view of the `roi` region of the same data, the view stays mutably borrowed while the result is alive
+	pub fn roi_mut(&mut self, roi: Rect) -> Result<BorrowedMatMut> {
+		unsafe { roi_unbound(&self.inner, roi) }
+			.map(|mat| unsafe { BorrowedMatMut::new(mat) })
+	}
+}
+
+impl ToInputArray for BorrowedMatMut<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl ToInputArray for &BorrowedMatMut<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl ToOutputArray for BorrowedMatMut<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		_OutputArray::from_mat_mut(&mut self.inner)
+	}
+}
+
+impl ToOutputArray for &mut BorrowedMatMut<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		(*self).output_array()
+	}
+}
+
+impl ToInputOutputArray for BorrowedMatMut<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		_InputOutputArray::from_mat_mut(&mut self.inner)
+	}
+}
+
+impl ToInputOutputArray for &mut BorrowedMatMut<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		(*self).input_output_array()
+	}
+}
+
+impl fmt::Debug for BorrowedMatMut<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
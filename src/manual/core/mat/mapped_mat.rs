@@ -0,0 +1,144 @@
+use std::{
+	fmt,
+	marker::PhantomData,
+};
+
+use crate::{
+	core::{
+		self,
+		_InputArray,
+		_InputOutputArray,
+		_OutputArray,
+		Mat,
+		ToInputArray,
+		ToInputOutputArray,
+		ToOutputArray,
+		UMat,
+		UMatTrait,
+	},
+	prelude::*,
+	Result,
+};
+
+/// Read-only `Mat` mapping of the `UMat` data to the host memory, created by `UMatTraitManual::map_read()`
+///
+/// The `UMat` stays borrowed while the mapping is alive and is unmapped when the guard is dropped, so it's not
+/// possible to pass the `UMat` as an output array to an OpenCL-backed function while its data is mapped. Same as
+/// `BorrowedMat` the guard doesn't expose the underlying `Mat` because the headers derived from it would keep the
+/// `UMat` mapped after the guard is dropped, only the methods that return borrowed data are provided.
+pub struct MappedMat<'u> {
+	inner: Mat,
+	_d: PhantomData<&'u UMat>,
+}
+
+impl<'u> MappedMat<'u> {
+	pub(crate) fn new(umat: &'u (impl UMatTrait + ?Sized)) -> Result<Self> {
+		#[cfg(not(feature = "opencv-4"))]
+		let flags = core::ACCESS_READ;
+		#[cfg(feature = "opencv-4")]
+		let flags = core::AccessFlag::ACCESS_READ;
+		umat.get_mat(flags)
+			.map(|inner| Self { inner, _d: PhantomData })
+	}
+
+	/// Underlying header for the crate internal read-only access, must not outlive the guard
+	#[inline]
+	pub(crate) fn as_mat(&self) -> &Mat {
+		&self.inner
+	}
+
+	mat_view_forward!();
+}
+
+impl ToInputArray for MappedMat<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl ToInputArray for &MappedMat<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl fmt::Debug for MappedMat<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
+
+/// Mutable `Mat` mapping of the `UMat` data to the host memory, created by `UMatTraitManual::map_write()`
+///
+/// The `UMat` stays mutably borrowed while the mapping is alive, the changes are visible in the `UMat` once the guard
+/// is dropped. The data is mapped for both reading and writing because the guard gives access to the existing values.
+/// See `MappedMat` for the reasons of the restricted API.
+pub struct MappedMatMut<'u> {
+	inner: Mat,
+	_d: PhantomData<&'u mut UMat>,
+}
+
+impl<'u> MappedMatMut<'u> {
+	pub(crate) fn new(umat: &'u mut (impl UMatTrait + ?Sized)) -> Result<Self> {
+		#[cfg(not(feature = "opencv-4"))]
+		let flags = core::ACCESS_RW;
+		#[cfg(feature = "opencv-4")]
+		let flags = core::AccessFlag::ACCESS_RW;
+		umat.get_mat(flags)
+			.map(|inner| Self { inner, _d: PhantomData })
+	}
+
+	mat_view_forward!();
+
+	mat_view_forward_mut!();
+}
+
+impl ToInputArray for MappedMatMut<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl ToInputArray for &MappedMatMut<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl ToOutputArray for MappedMatMut<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		_OutputArray::from_mat_mut(&mut self.inner)
+	}
+}
+
+impl ToOutputArray for &mut MappedMatMut<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		(*self).output_array()
+	}
+}
+
+impl ToInputOutputArray for MappedMatMut<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		_InputOutputArray::from_mat_mut(&mut self.inner)
+	}
+}
+
+impl ToInputOutputArray for &mut MappedMatMut<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		(*self).input_output_array()
+	}
+}
+
+impl fmt::Debug for MappedMatMut<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
@@ -6,19 +6,36 @@ use std::{
 };
 
 use crate::{
-	core::{_InputArray, Mat, MatTrait, ToInputArray},
+	core::{
+		_InputArray,
+		_InputOutputArray,
+		_OutputArray,
+		Mat,
+		MatRef,
+		MatRefMut,
+		MatTrait,
+		Point,
+		Rect,
+		Scalar,
+		Size,
+		ToInputArray,
+		ToInputOutputArray,
+		ToOutputArray,
+	},
 	Error,
 	Result,
+	sys,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
 };
 
-use super::{DataType, match_dims, match_format, match_is_continuous, match_total, MatTraitManual};
+use super::{DataType, match_dims, match_format, match_indices, match_is_continuous, match_total, MatTraitManual};
 
 /// [docs.opencv.org](https://docs.opencv.org/master/df/dfc/classcv_1_1Mat__.html)
 ///
 /// This struct is freely convertible into and from `Mat` using `into` and `try_from` methods. You might want
-/// to convert `Mat` to `Mat_` before calling typed methods (like `at`, `data_typed`) when more performance is
-/// required because this way you will skip the data type checks (still WIP, not all methods are covered).
+/// to convert `Mat` to `Mat_` before calling typed methods (like `at_2d`, `at_row`, `data_typed`) when more
+/// performance is required because this way you will skip the data type checks. When passed as an output
+/// argument its element type is fixed, so OpenCV functions can't change it.
 pub struct Mat_<T> {
 	inner: Mat,
 	_type: PhantomData<T>,
@@ -42,6 +59,39 @@ impl<T: DataType> From<Mat_<T>> for Mat {
 }
 
 impl<T: DataType> Mat_<T> {
+	/// Create a new zero-initialized 2D `Mat_`
+	pub fn zeros(rows: i32, cols: i32) -> Result<Self> {
+		Mat::new_rows_cols_with_default(rows, cols, T::typ(), Scalar::all(0.))
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Create a new zero-initialized N-dimensional `Mat_`
+	pub fn zeros_nd(sizes: &[i32]) -> Result<Self> {
+		Mat::new_nd_with_default(sizes, T::typ(), Scalar::all(0.))
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Create a new single-row `Mat_` copying the data from the slice
+	#[inline]
+	pub fn from_slice(s: &[T]) -> Result<Self> {
+		Mat::from_slice(s)
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Create a new 2D `Mat_` copying the data from the slice of rows, all rows must have the same length
+	#[inline]
+	pub fn from_slice_2d(s: &[impl AsRef<[T]>]) -> Result<Self> {
+		Mat::from_slice_2d(s)
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// # Safety
+	/// Caller must ensure that the type of `mat` matches `T`
+	#[inline]
+	unsafe fn from_untyped_unchecked(mat: Mat) -> Self {
+		Self { inner: mat, _type: PhantomData }
+	}
+
 	#[inline]
 	pub fn into_untyped(self) -> Mat {
 		self.into()
@@ -78,6 +128,66 @@ impl<T: DataType> Mat_<T> {
 		unsafe { self.at_unchecked_mut(i0) }
 	}
 
+	#[inline(always)]
+	pub fn at_2d(&self, row: i32, col: i32) -> Result<&T> {
+		match_indices(self, &[row, col])
+			.and_then(|_| unsafe { self.at_2d_unchecked(row, col) })
+	}
+
+	#[inline(always)]
+	pub fn at_2d_mut(&mut self, row: i32, col: i32) -> Result<&mut T> {
+		match_indices(self, &[row, col])?;
+		unsafe { self.at_2d_unchecked_mut(row, col) }
+	}
+
+	#[inline(always)]
+	pub fn at_pt(&self, pt: Point) -> Result<&T> {
+		self.at_2d(pt.y, pt.x)
+	}
+
+	#[inline(always)]
+	pub fn at_pt_mut(&mut self, pt: Point) -> Result<&mut T> {
+		self.at_2d_mut(pt.y, pt.x)
+	}
+
+	#[inline(always)]
+	pub fn at_3d(&self, i0: i32, i1: i32, i2: i32) -> Result<&T> {
+		match_indices(self, &[i0, i1, i2])
+			.and_then(|_| unsafe { self.at_3d_unchecked(i0, i1, i2) })
+	}
+
+	#[inline(always)]
+	pub fn at_3d_mut(&mut self, i0: i32, i1: i32, i2: i32) -> Result<&mut T> {
+		match_indices(self, &[i0, i1, i2])?;
+		unsafe { self.at_3d_unchecked_mut(i0, i1, i2) }
+	}
+
+	#[inline(always)]
+	pub fn at_nd(&self, idx: &[i32]) -> Result<&T> {
+		match_indices(self, idx)
+			.and_then(|_| unsafe { self.at_nd_unchecked(idx) })
+	}
+
+	#[inline(always)]
+	pub fn at_nd_mut(&mut self, idx: &[i32]) -> Result<&mut T> {
+		match_indices(self, idx)?;
+		unsafe { self.at_nd_unchecked_mut(idx) }
+	}
+
+	/// Return a complete read-only row
+	#[inline]
+	pub fn at_row(&self, row: i32) -> Result<&[T]> {
+		match_indices(self, &[row, 0])
+			.and_then(|_| unsafe { self.at_row_unchecked(row) })
+	}
+
+	/// Return a complete writeable row
+	#[inline]
+	pub fn at_row_mut(&mut self, row: i32) -> Result<&mut [T]> {
+		match_indices(self, &[row, 0])?;
+		unsafe { self.at_row_unchecked_mut(row) }
+	}
+
 	pub fn data_typed(&self) -> Result<&[T]> {
 		match_is_continuous(self)
 			.and_then(|_| unsafe { self.data_typed_unchecked() })
@@ -87,6 +197,42 @@ impl<T: DataType> Mat_<T> {
 		match_is_continuous(self)?;
 		unsafe { self.data_typed_unchecked_mut() }
 	}
+
+	pub fn to_vec_2d(&self) -> Result<Vec<Vec<T>>> {
+		match_dims(self, 2)?;
+		(0..self.rows())
+			.map(|row_n| unsafe { self.at_row_unchecked(row_n) }.map(|row| row.to_vec()))
+			.collect()
+	}
+
+	/// Read-only typed view of the `roi` region of this `Mat_`, the data is shared and not copied
+	#[inline]
+	pub fn roi(&self, roi: Rect) -> Result<MatRef_<T>> {
+		self.roi_ref(roi)
+			.map(|inner| MatRef_ { inner, _type: PhantomData })
+	}
+
+	/// Mutable typed view of the `roi` region of this `Mat_`, the data is shared and not copied
+	#[inline]
+	pub fn roi_mut(&mut self, roi: Rect) -> Result<MatRefMut_<T>> {
+		MatTraitManual::roi_mut(self, roi)
+			.map(|inner| MatRefMut_ { inner, _type: PhantomData })
+	}
+
+	/// Create a full copy of the matrix and the underlying data
+	#[inline]
+	pub fn try_clone(&self) -> Result<Self> {
+		self.inner.try_clone()
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+}
+
+impl<T: DataType> Clone for Mat_<T> {
+	#[inline]
+	/// Calls try_clone() and panics if that fails
+	fn clone(&self) -> Self {
+		self.try_clone().expect("Cannot clone Mat_")
+	}
 }
 
 impl<T> MatTrait for Mat_<T> {
@@ -120,11 +266,65 @@ impl<T> Boxed for Mat_<T> {
 }
 
 impl<T> ToInputArray for Mat_<T> {
+	#[inline]
 	fn input_array(&self) -> Result<_InputArray, Error> {
 		self.inner.input_array()
 	}
 }
 
+impl<T> ToInputArray for &Mat_<T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+/// Creates the output array with the element type fixed to `T`
+fn typed_output_array<T: DataType>(mat: &mut impl MatTrait) -> Result<_OutputArray> {
+	extern "C" { fn cv_manual_Mat__output_array(instance: *mut c_void, typ: i32) -> sys::Result<*mut c_void>; }
+	unsafe { cv_manual_Mat__output_array(mat.as_raw_mut_Mat(), T::typ()) }
+		.into_result()
+		.map(|ptr| unsafe { _OutputArray::from_raw(ptr) })
+}
+
+/// Creates the input-output array with the element type fixed to `T`
+fn typed_input_output_array<T: DataType>(mat: &mut impl MatTrait) -> Result<_InputOutputArray> {
+	extern "C" { fn cv_manual_Mat__input_output_array(instance: *mut c_void, typ: i32) -> sys::Result<*mut c_void>; }
+	unsafe { cv_manual_Mat__input_output_array(mat.as_raw_mut_Mat(), T::typ()) }
+		.into_result()
+		.map(|ptr| unsafe { _InputOutputArray::from_raw(ptr) })
+}
+
+/// Output array has a fixed type just like the one created from `Mat_` in C++ so that OpenCV functions can't
+/// change the element type of `Mat_`
+impl<T: DataType> ToOutputArray for Mat_<T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		typed_output_array::<T>(self)
+	}
+}
+
+impl<T: DataType> ToOutputArray for &mut Mat_<T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		(*self).output_array()
+	}
+}
+
+impl<T: DataType> ToInputOutputArray for Mat_<T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		typed_input_output_array::<T>(self)
+	}
+}
+
+impl<T: DataType> ToInputOutputArray for &mut Mat_<T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		(*self).input_output_array()
+	}
+}
+
 impl<T> OpenCVType<'_> for Mat_<T> {
 	type Arg = Self;
 	type ExternReceive = *mut c_void;
@@ -185,3 +385,275 @@ impl<T> fmt::Debug for Mat_<T> {
 		self.inner.fmt(f)
 	}
 }
+
+/// Read-only typed view into the data of a `Mat_`, created by `Mat_::roi()`
+///
+/// Works like `MatRef`, but provides the typed accessors of `Mat_` that skip the element type checks. Same as
+/// `MatRef` it doesn't implement `MatTrait` and only exposes the read-only accessors.
+pub struct MatRef_<'m, T> {
+	inner: MatRef<'m>,
+	_type: PhantomData<T>,
+}
+
+impl<'m, T: DataType> MatRef_<'m, T> {
+	#[inline(always)]
+	fn as_mat(&self) -> &Mat {
+		self.inner.as_mat()
+	}
+
+	#[inline]
+	pub fn rows(&self) -> i32 {
+		self.as_mat().rows()
+	}
+
+	#[inline]
+	pub fn cols(&self) -> i32 {
+		self.as_mat().cols()
+	}
+
+	#[inline]
+	pub fn size(&self) -> Result<Size> {
+		self.as_mat().size()
+	}
+
+	#[inline]
+	pub fn total(&self) -> Result<usize> {
+		self.as_mat().total()
+	}
+
+	#[inline]
+	pub fn is_continuous(&self) -> Result<bool> {
+		self.as_mat().is_continuous()
+	}
+
+	#[inline(always)]
+	pub fn at(&self, i0: i32) -> Result<&T> {
+		let mat = self.as_mat();
+		match_dims(mat, 2)
+			.and_then(|_| match_total(mat, i0))
+			.and_then(|_| unsafe { mat.at_unchecked(i0) })
+	}
+
+	#[inline(always)]
+	pub fn at_2d(&self, row: i32, col: i32) -> Result<&T> {
+		let mat = self.as_mat();
+		match_indices(mat, &[row, col])
+			.and_then(|_| unsafe { mat.at_2d_unchecked(row, col) })
+	}
+
+	#[inline(always)]
+	pub fn at_pt(&self, pt: Point) -> Result<&T> {
+		self.at_2d(pt.y, pt.x)
+	}
+
+	/// Return a complete read-only row
+	#[inline]
+	pub fn at_row(&self, row: i32) -> Result<&[T]> {
+		let mat = self.as_mat();
+		match_indices(mat, &[row, 0])
+			.and_then(|_| unsafe { mat.at_row_unchecked(row) })
+	}
+
+	pub fn data_typed(&self) -> Result<&[T]> {
+		let mat = self.as_mat();
+		match_is_continuous(mat)
+			.and_then(|_| unsafe { mat.data_typed_unchecked() })
+	}
+
+	pub fn to_vec_2d(&self) -> Result<Vec<Vec<T>>> {
+		let mat = self.as_mat();
+		match_dims(mat, 2)?;
+		(0..mat.rows())
+			.map(|row_n| unsafe { mat.at_row_unchecked(row_n) }.map(|row| row.to_vec()))
+			.collect()
+	}
+
+	/// Read-only typed view of the `roi` region of this view
+	#[inline]
+	pub fn roi(&self, roi: Rect) -> Result<MatRef_<'m, T>> {
+		self.inner.roi(roi)
+			.map(|inner| MatRef_ { inner, _type: PhantomData })
+	}
+
+	/// Copy the data of the view into a new independent `Mat_`
+	#[inline]
+	pub fn to_mat(&self) -> Result<Mat_<T>> {
+		self.inner.to_mat()
+			.map(|mat| unsafe { Mat_::from_untyped_unchecked(mat) })
+	}
+}
+
+impl<T> ToInputArray for MatRef_<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T> ToInputArray for &MatRef_<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl<T> fmt::Debug for MatRef_<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
+
+/// Mutable typed view into the data of a `Mat_`, created by `Mat_::roi_mut()`
+///
+/// Works like `MatRefMut`, but provides the typed accessors of `Mat_` that skip the element type checks. When passed
+/// as an output argument its element type is fixed, same as for `Mat_`.
+pub struct MatRefMut_<'m, T> {
+	inner: MatRefMut<'m>,
+	_type: PhantomData<T>,
+}
+
+impl<T: DataType> MatRefMut_<'_, T> {
+	#[inline(always)]
+	pub fn at(&self, i0: i32) -> Result<&T> {
+		match_dims(self, 2)
+			.and_then(|_| match_total(self, i0))
+			.and_then(|_| unsafe { self.at_unchecked(i0) })
+	}
+
+	#[inline(always)]
+	pub fn at_mut(&mut self, i0: i32) -> Result<&mut T> {
+		match_dims(self, 2)
+			.and_then(|_| match_total(self, i0))?;
+		unsafe { self.at_unchecked_mut(i0) }
+	}
+
+	#[inline(always)]
+	pub fn at_2d(&self, row: i32, col: i32) -> Result<&T> {
+		match_indices(self, &[row, col])
+			.and_then(|_| unsafe { self.at_2d_unchecked(row, col) })
+	}
+
+	#[inline(always)]
+	pub fn at_2d_mut(&mut self, row: i32, col: i32) -> Result<&mut T> {
+		match_indices(self, &[row, col])?;
+		unsafe { self.at_2d_unchecked_mut(row, col) }
+	}
+
+	#[inline(always)]
+	pub fn at_pt(&self, pt: Point) -> Result<&T> {
+		self.at_2d(pt.y, pt.x)
+	}
+
+	#[inline(always)]
+	pub fn at_pt_mut(&mut self, pt: Point) -> Result<&mut T> {
+		self.at_2d_mut(pt.y, pt.x)
+	}
+
+	/// Return a complete read-only row
+	#[inline]
+	pub fn at_row(&self, row: i32) -> Result<&[T]> {
+		match_indices(self, &[row, 0])
+			.and_then(|_| unsafe { self.at_row_unchecked(row) })
+	}
+
+	/// Return a complete writeable row
+	#[inline]
+	pub fn at_row_mut(&mut self, row: i32) -> Result<&mut [T]> {
+		match_indices(self, &[row, 0])?;
+		unsafe { self.at_row_unchecked_mut(row) }
+	}
+
+	pub fn data_typed(&self) -> Result<&[T]> {
+		match_is_continuous(self)
+			.and_then(|_| unsafe { self.data_typed_unchecked() })
+	}
+
+	pub fn data_typed_mut(&mut self) -> Result<&mut [T]> {
+		match_is_continuous(self)?;
+		unsafe { self.data_typed_unchecked_mut() }
+	}
+
+	pub fn to_vec_2d(&self) -> Result<Vec<Vec<T>>> {
+		match_dims(self, 2)?;
+		(0..self.rows())
+			.map(|row_n| unsafe { self.at_row_unchecked(row_n) }.map(|row| row.to_vec()))
+			.collect()
+	}
+
+	/// Read-only typed view of the `roi` region of this view
+	#[inline]
+	pub fn roi(&self, roi: Rect) -> Result<MatRef_<T>> {
+		self.roi_ref(roi)
+			.map(|inner| MatRef_ { inner, _type: PhantomData })
+	}
+
+	/// Mutable typed view of the `roi` region of this view
+	#[inline]
+	pub fn roi_mut(&mut self, roi: Rect) -> Result<MatRefMut_<T>> {
+		MatTraitManual::roi_mut(self, roi)
+			.map(|inner| MatRefMut_ { inner, _type: PhantomData })
+	}
+
+	/// Copy the data of the view into a new independent `Mat_`
+	#[inline]
+	pub fn to_mat(&self) -> Result<Mat_<T>> {
+		self.inner.to_mat()
+			.map(|mat| unsafe { Mat_::from_untyped_unchecked(mat) })
+	}
+}
+
+impl<T> MatTrait for MatRefMut_<'_, T> {
+	#[inline]
+	fn as_raw_Mat(&self) -> *const c_void { self.inner.as_raw_Mat() }
+
+	#[inline]
+	fn as_raw_mut_Mat(&mut self) -> *mut c_void { self.inner.as_raw_mut_Mat() }
+}
+
+impl<T> ToInputArray for MatRefMut_<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T> ToInputArray for &MatRefMut_<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl<T: DataType> ToOutputArray for MatRefMut_<'_, T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		typed_output_array::<T>(self)
+	}
+}
+
+impl<T: DataType> ToOutputArray for &mut MatRefMut_<'_, T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		(*self).output_array()
+	}
+}
+
+impl<T: DataType> ToInputOutputArray for MatRefMut_<'_, T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		typed_input_output_array::<T>(self)
+	}
+}
+
+impl<T: DataType> ToInputOutputArray for &mut MatRefMut_<'_, T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		(*self).input_output_array()
+	}
+}
+
+impl<T> fmt::Debug for MatRefMut_<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
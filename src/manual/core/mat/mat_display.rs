@@ -0,0 +1,135 @@
+use std::{
+	convert::TryFrom,
+	ffi::c_void,
+	fmt,
+};
+
+use crate::{
+	core::{self, Mat, Mat_, MatTrait, UMat, UMatTraitManual},
+	Error,
+	Result,
+	sys,
+	traits::OpenCVType,
+};
+
+/// Number of rows and columns that are shown by default, bigger matrices are cut to the top-left corner
+const DEFAULT_LIMIT: (i32, i32) = (32, 32);
+
+/// Output style of the `Mat` values, `Formatter_FormatType` is an enum only in the OpenCV 4 bindings
+#[cfg(feature = "opencv-4")]
+pub(crate) type FormatType = core::Formatter_FormatType;
+#[cfg(not(feature = "opencv-4"))]
+pub(crate) type FormatType = i32;
+
+#[cfg(feature = "opencv-4")]
+const FMT_DEFAULT: FormatType = core::Formatter_FormatType::FMT_DEFAULT;
+#[cfg(not(feature = "opencv-4"))]
+const FMT_DEFAULT: FormatType = core::Formatter_FMT_DEFAULT;
+#[cfg(feature = "opencv-4")]
+const FMT_NUMPY: FormatType = core::Formatter_FormatType::FMT_NUMPY;
+#[cfg(not(feature = "opencv-4"))]
+const FMT_NUMPY: FormatType = core::Formatter_FMT_NUMPY;
+
+/// Adapter for displaying the `Mat` values in the specified style, created by `MatTraitManual::display_as()`
+///
+/// Matrices with more than 32 rows or columns are cut to the top-left corner by default with a note about the full
+/// size appended, use `limit()` or `unlimited()` to change that. Precision of the floating point values can be set
+/// with the standard format syntax, e.g. `{:.3}`, but unlike for Rust floats it sets the number of significant
+/// digits, not the digits after the decimal point, because it's passed as is to `cv::Formatter::set32fPrecision()`
+/// and `set64fPrecision()`. So `1.2345` is shown as `1.23` with `{:.3}`.
+pub struct MatDisplay<'m, M: ?Sized> {
+	mat: &'m M,
+	fmt: FormatType,
+	limit: Option<(i32, i32)>,
+}
+
+impl<'m, M: MatTrait + ?Sized> MatDisplay<'m, M> {
+	#[inline]
+	pub fn new(mat: &'m M, fmt: FormatType) -> Self {
+		Self { mat, fmt, limit: Some(DEFAULT_LIMIT) }
+	}
+
+	/// Show at most `rows` rows and `cols` columns of the matrix
+	#[inline]
+	pub fn limit(mut self, rows: i32, cols: i32) -> Self {
+		self.limit = Some((rows.max(1), cols.max(1)));
+		self
+	}
+
+	/// Show the whole matrix regardless of its size
+	#[inline]
+	pub fn unlimited(mut self) -> Self {
+		self.limit = None;
+		self
+	}
+}
+
+impl<M: MatTrait + ?Sized> fmt::Display for MatDisplay<'_, M> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let dims = self.mat.dims();
+		if dims > 2 {
+			// cv::Formatter only handles 2D matrices
+			return match self.mat.total() {
+				Ok(total) => write!(f, "<{}-dimensional Mat with {} elements>", dims, total),
+				Err(e) => write_error(f, &e),
+			};
+		}
+		// cv::Formatter precision is the number of significant digits
+		let precision = f.precision().map_or(-1, |p| i32::try_from(p).unwrap_or(i32::MAX));
+		let (rows, cols) = (self.mat.rows(), self.mat.cols());
+		let (show_rows, show_cols) = self.limit.map_or((rows, cols), |(max_rows, max_cols)| (rows.min(max_rows), cols.min(max_cols)));
+		let elided = show_rows < rows || show_cols < cols;
+		let out = if elided {
+			self.mat.row_bounds(0, show_rows)
+				.and_then(|part| part.col_bounds(0, show_cols))
+				.and_then(|part| format_mat(&part, self.fmt, precision))
+		} else {
+			format_mat(self.mat, self.fmt, precision)
+		};
+		match out {
+			Ok(out) => f.write_str(&out)?,
+			Err(e) => return write_error(f, &e),
+		}
+		if elided {
+			write!(f, "\n... showing {}x{} of {}x{} elements", show_rows, show_cols, rows, cols)?;
+		}
+		Ok(())
+	}
+}
+
+/// `fmt::Error` would make `to_string()` panic, so the OpenCV errors are shown in place of the values instead
+fn write_error(f: &mut fmt::Formatter, e: &Error) -> fmt::Result {
+	write!(f, "<Mat formatting failed: {}>", e)
+}
+
+fn format_mat(mat: &(impl MatTrait + ?Sized), fmt: FormatType, precision: i32) -> Result<String> {
+	extern "C" { fn cv_manual_Mat_format(instance: *const c_void, fmt: i32, precision: i32) -> sys::Result<*mut c_void>; }
+	unsafe { cv_manual_Mat_format(mat.as_raw_Mat(), fmt as i32, precision) }
+		.into_result()
+		.map(|s| unsafe { String::opencv_from_extern(s) })
+}
+
+/// `{}` outputs the values in the default OpenCV style, `{:#}` in the NumPy style
+impl fmt::Display for Mat {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let fmt = if f.alternate() { FMT_NUMPY } else { FMT_DEFAULT };
+		fmt::Display::fmt(&MatDisplay::new(self, fmt), f)
+	}
+}
+
+impl<T> fmt::Display for Mat_<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let fmt = if f.alternate() { FMT_NUMPY } else { FMT_DEFAULT };
+		fmt::Display::fmt(&MatDisplay::new(self, fmt), f)
+	}
+}
+
+/// Maps the `UMat` to the host memory for reading and displays it like `Mat`
+impl fmt::Display for UMat {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.map_read() {
+			Ok(mapped) => fmt::Display::fmt(mapped.as_mat(), f),
+			Err(e) => write_error(f, &e),
+		}
+	}
+}
@@ -0,0 +1,206 @@
+//! Conversions between `Mat` and `image` crate buffers, enabled by the `image` cargo feature
+
+use std::{
+	convert::TryInto,
+	mem,
+	ops::{Deref, DerefMut},
+	slice,
+};
+
+use image::{ImageBuffer, Luma, Pixel, Rgb, Rgba};
+
+use crate::{
+	core::{self, BorrowedMat, BorrowedMatMut, Mat, Mat_AUTO_STEP, MatTrait, Vec3b, Vec3f, Vec4b},
+	Error,
+	Result,
+};
+
+use super::{DataType, match_dims_2d, match_format, MatTraitManual};
+
+/// Order of the channels in the data of the color `Mat`
+///
+/// OpenCV functions (e.g. `imread()`, `imshow()` or `VideoCapture`) produce and expect BGR data, but the `image` crate
+/// always uses RGB. The order is ignored for the grayscale images.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChannelOrder {
+	/// Blue, green, red (and alpha), OpenCV default, channels are swapped during the conversion
+	Bgr,
+	/// Red, green, blue (and alpha), same as in `image`, the data is copied as is
+	Rgb,
+}
+
+/// `image` pixel type that has the `Mat` element type with exactly the same memory layout
+///
+/// # Safety
+/// `Elem` must have the size of `CHANNEL_COUNT` subpixels and the same alignment as `Subpixel`, the image data is
+/// reinterpreted as `Mat` elements and back without copying.
+pub unsafe trait MatPixel: Pixel + 'static {
+	/// Element type of the corresponding `Mat`
+	type Elem: DataType;
+}
+
+macro_rules! mat_pixel {
+	($pixel: ty, $elem: ty) => {
+		unsafe impl MatPixel for $pixel {
+			type Elem = $elem;
+		}
+	};
+}
+
+mat_pixel!(Luma<u8>, u8);
+mat_pixel!(Luma<u16>, u16);
+mat_pixel!(Rgb<u8>, Vec3b);
+mat_pixel!(Rgba<u8>, Vec4b);
+mat_pixel!(Rgb<f32>, Vec3f);
+
+/// Checks the `MatPixel` layout requirements, it's a constant expression so it's optimized away
+#[inline(always)]
+fn assert_layout<P: MatPixel>() {
+	assert_eq!(mem::size_of::<P::Elem>(), mem::size_of::<P::Subpixel>() * P::CHANNEL_COUNT as usize, "MatPixel element size mismatch");
+	assert_eq!(mem::align_of::<P::Elem>(), mem::align_of::<P::Subpixel>(), "MatPixel element alignment mismatch");
+}
+
+#[inline]
+fn subpixels_as_elems<P: MatPixel>(s: &[P::Subpixel]) -> &[P::Elem] {
+	assert_layout::<P>();
+	unsafe { slice::from_raw_parts(s.as_ptr() as *const P::Elem, s.len() / P::CHANNEL_COUNT as usize) }
+}
+
+#[inline]
+fn subpixels_as_elems_mut<P: MatPixel>(s: &mut [P::Subpixel]) -> &mut [P::Elem] {
+	assert_layout::<P>();
+	unsafe { slice::from_raw_parts_mut(s.as_mut_ptr() as *mut P::Elem, s.len() / P::CHANNEL_COUNT as usize) }
+}
+
+#[inline]
+fn elems_as_subpixels<P: MatPixel>(s: &[P::Elem]) -> &[P::Subpixel] {
+	assert_layout::<P>();
+	unsafe { slice::from_raw_parts(s.as_ptr() as *const P::Subpixel, s.len() * P::CHANNEL_COUNT as usize) }
+}
+
+#[inline]
+fn elems_as_subpixels_mut<P: MatPixel>(s: &mut [P::Elem]) -> &mut [P::Subpixel] {
+	assert_layout::<P>();
+	unsafe { slice::from_raw_parts_mut(s.as_mut_ptr() as *mut P::Subpixel, s.len() * P::CHANNEL_COUNT as usize) }
+}
+
+/// Swap red and blue channels in place if the data is in BGR order
+fn convert_order<P: MatPixel>(s: &mut [P::Subpixel], order: ChannelOrder) {
+	let channels = P::CHANNEL_COUNT as usize;
+	if order == ChannelOrder::Bgr && channels >= 3 {
+		s.chunks_exact_mut(channels).for_each(|px| px.swap(0, 2));
+	}
+}
+
+fn image_size(width: u32, height: u32) -> Result<(i32, i32)> {
+	match (height.try_into(), width.try_into()) {
+		(Ok(rows), Ok(cols)) => Ok((rows, cols)),
+		_ => Err(Error::new(core::StsOutOfRange, format!("Image dimensions: {}x{} are too large for Mat", width, height))),
+	}
+}
+
+/// Check that the image container holds enough data and return the number of subpixels that belong to the image
+fn image_len<P: MatPixel>(width: u32, height: u32, len: usize) -> Result<usize> {
+	let required = width as usize * height as usize * P::CHANNEL_COUNT as usize;
+	if len >= required {
+		Ok(required)
+	} else {
+		Err(Error::new(core::StsUnmatchedSizes, format!("Image buffer of {} elements is too small for {}x{} image", len, width, height)))
+	}
+}
+
+impl Mat {
+	/// Create a new `Mat` copying the data from the `ImageBuffer`, `order` specifies the channel order of the
+	/// resulting `Mat`
+	pub fn from_image<P, C>(img: &ImageBuffer<P, C>, order: ChannelOrder) -> Result<Self>
+		where
+			P: MatPixel,
+			C: Deref<Target=[P::Subpixel]>,
+	{
+		let (width, height) = img.dimensions();
+		let (rows, cols) = image_size(width, height)?;
+		let src = img.as_raw();
+		let src = &src[..image_len::<P>(width, height, src.len())?];
+		let mut out = unsafe { Self::new_rows_cols(rows, cols, P::Elem::typ()) }?;
+		if !src.is_empty() {
+			let dst = out.data_typed_mut::<P::Elem>()?;
+			dst.copy_from_slice(subpixels_as_elems::<P>(src));
+			convert_order::<P>(elems_as_subpixels_mut::<P>(dst), order);
+		}
+		Ok(out)
+	}
+
+	/// Create a read-only `Mat` view of the `ImageBuffer` data without copying it
+	///
+	/// The data can't be reordered in place, so it's returned together with its channel order, which is always
+	/// `ChannelOrder::Rgb`. Convert the view with `imgproc::cvt_color()` before passing it to the functions expecting BGR.
+	pub fn from_image_ref<P, C>(img: &ImageBuffer<P, C>) -> Result<(BorrowedMat, ChannelOrder)>
+		where
+			P: MatPixel,
+			C: Deref<Target=[P::Subpixel]>,
+	{
+		let (width, height) = img.dimensions();
+		let (rows, cols) = image_size(width, height)?;
+		let src = img.as_raw();
+		let src = &src[..image_len::<P>(width, height, src.len())?];
+		Self::new_rows_cols_with_slice(rows, cols, subpixels_as_elems::<P>(src), Mat_AUTO_STEP)
+			.map(|mat| (mat, ChannelOrder::Rgb))
+	}
+
+	/// Create a mutable `Mat` view of the `ImageBuffer` data without copying it
+	///
+	/// Returned together with the channel order of the data like in `from_image_ref()`, the values written to the view
+	/// must also be in that order.
+	pub fn from_image_mut<P, C>(img: &mut ImageBuffer<P, C>) -> Result<(BorrowedMatMut, ChannelOrder)>
+		where
+			P: MatPixel,
+			C: DerefMut<Target=[P::Subpixel]>,
+	{
+		let (width, height) = img.dimensions();
+		let (rows, cols) = image_size(width, height)?;
+		let len = image_len::<P>(width, height, img.as_raw().len())?;
+		let src = &mut img.deref_mut()[..len];
+		Self::new_rows_cols_with_slice_mut(rows, cols, subpixels_as_elems_mut::<P>(src), Mat_AUTO_STEP)
+			.map(|mat| (mat, ChannelOrder::Rgb))
+	}
+}
+
+pub(super) fn to_image<P: MatPixel>(mat: &(impl MatTrait + ?Sized), order: ChannelOrder) -> Result<ImageBuffer<P, Vec<P::Subpixel>>> {
+	let (width, height) = match_image::<P>(mat)?;
+	let mut data = Vec::with_capacity(width as usize * height as usize * P::CHANNEL_COUNT as usize);
+	for row in mat.iter_rows::<P::Elem>()? {
+		data.extend_from_slice(elems_as_subpixels::<P>(row));
+	}
+	convert_order::<P>(&mut data, order);
+	ImageBuffer::from_raw(width, height, data)
+		.ok_or_else(|| Error::new(core::StsUnmatchedSizes, format!("Can't create {}x{} image", width, height)))
+}
+
+/// Check the `Mat` type and dimensions and return the size of the corresponding image
+fn match_image<P: MatPixel>(mat: &(impl MatTrait + ?Sized)) -> Result<(u32, u32)> {
+	match_format::<P::Elem>(mat.typ()?)?;
+	match_dims_2d(mat)?;
+	Ok((mat.cols() as u32, mat.rows() as u32))
+}
+
+pub(super) fn as_image<P: MatPixel>(mat: &(impl MatTrait + ?Sized)) -> Result<ImageBuffer<P, &[P::Subpixel]>> {
+	let (width, height) = match_image::<P>(mat)?;
+	let data = if mat.empty()? {
+		&[]
+	} else {
+		elems_as_subpixels::<P>(mat.data_typed::<P::Elem>()?)
+	};
+	ImageBuffer::from_raw(width, height, data)
+		.ok_or_else(|| Error::new(core::StsUnmatchedSizes, format!("Can't create {}x{} image", width, height)))
+}
+
+pub(super) fn as_image_mut<P: MatPixel>(mat: &mut (impl MatTrait + ?Sized)) -> Result<ImageBuffer<P, &mut [P::Subpixel]>> {
+	let (width, height) = match_image::<P>(mat)?;
+	let data = if mat.empty()? {
+		&mut []
+	} else {
+		elems_as_subpixels_mut::<P>(mat.data_typed_mut::<P::Elem>()?)
+	};
+	ImageBuffer::from_raw(width, height, data)
+		.ok_or_else(|| Error::new(core::StsUnmatchedSizes, format!("Can't create {}x{} image", width, height)))
+}
@@ -0,0 +1,270 @@
+use std::{
+	iter::FusedIterator,
+	marker::PhantomData,
+	ptr,
+	slice,
+};
+
+use crate::{
+	core::{MatTrait, Point},
+	Result,
+};
+
+use super::{DataType, match_dims_2d, match_format};
+
+/// Memory layout of the 2D `Mat` data, allows walking the rows using the step without FFI calls
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct MatLayout {
+	pub data: *mut u8,
+	/// Number of bytes between the starts of the consecutive rows
+	pub step: usize,
+	pub rows: i32,
+	pub cols: i32,
+}
+
+impl MatLayout {
+	pub fn new<T: DataType>(mat: &(impl MatTrait + ?Sized)) -> Result<Self> {
+		match_format::<T>(mat.typ()?)
+			.and_then(|_| match_dims_2d(mat))?;
+		let rows = mat.rows();
+		let cols = mat.cols();
+		Ok(if rows <= 0 || cols <= 0 {
+			Self { data: ptr::null_mut(), step: 0, rows: 0, cols: 0 }
+		} else {
+			Self { data: mat.data()? as *const u8 as *mut u8, step: mat.mat_step()[0], rows, cols }
+		})
+	}
+
+	/// # Safety
+	/// Caller must ensure that `row` is within bounds and `T` matches the `Mat` type
+	#[inline(always)]
+	pub unsafe fn row_ptr<T>(&self, row: i32) -> *mut T {
+		self.data.add(row as usize * self.step) as *mut T
+	}
+
+	/// # Safety
+	/// Caller must ensure that `row` and `col` are within bounds and `T` matches the `Mat` type
+	#[inline(always)]
+	pub unsafe fn elem_ptr<T>(&self, row: i32, col: i32) -> *mut T {
+		self.row_ptr::<T>(row).add(col as usize)
+	}
+
+	/// Split the layout into two non-overlapping row bands: `0..row` and `row..rows`, `row` must be within `0..=rows`
+	#[inline]
+	pub fn split_at_row(self, row: i32) -> (Self, Self) {
+		let tail = Self {
+			data: self.data.wrapping_add(row as usize * self.step),
+			rows: self.rows - row,
+			..self
+		};
+		(Self { rows: row, ..self }, tail)
+	}
+}
+
+/// Position of the iterator, walks the elements in row-major order
+#[derive(Copy, Clone, Debug)]
+struct IterPos {
+	row: i32,
+	col: i32,
+}
+
+impl IterPos {
+	#[inline(always)]
+	fn next(&mut self, layout: &MatLayout) -> Option<Point> {
+		if self.row >= layout.rows {
+			None
+		} else {
+			let out = Point::new(self.col, self.row);
+			self.col += 1;
+			if self.col >= layout.cols {
+				self.col = 0;
+				self.row += 1;
+			}
+			Some(out)
+		}
+	}
+
+	#[inline(always)]
+	fn remaining(&self, layout: &MatLayout) -> usize {
+		(layout.rows - self.row) as usize * layout.cols as usize - self.col as usize
+	}
+}
+
+/// Iterator over the elements of 2D `Mat`, created by `MatTraitManual::iter()`
+pub struct MatIter<'m, T> {
+	layout: MatLayout,
+	pos: IterPos,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T: DataType> MatIter<'m, T> {
+	pub fn new(mat: &'m (impl MatTrait + ?Sized)) -> Result<Self> {
+		MatLayout::new::<T>(mat)
+			.map(|layout| Self { layout, pos: IterPos { row: 0, col: 0 }, _d: PhantomData })
+	}
+}
+
+impl<'m, T: DataType> Iterator for MatIter<'m, T> {
+	type Item = (Point, &'m T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.pos.next(&self.layout)
+			.map(|pt| (pt, unsafe { &*self.layout.elem_ptr::<T>(pt.y, pt.x) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.pos.remaining(&self.layout);
+		(len, Some(len))
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatIter<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatIter<'_, T> {}
+
+unsafe impl<T: DataType + Sync> Send for MatIter<'_, T> {}
+
+/// Iterator over the mutable elements of 2D `Mat`, created by `MatTraitManual::iter_mut()`
+pub struct MatIterMut<'m, T> {
+	layout: MatLayout,
+	pos: IterPos,
+	_d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T: DataType> MatIterMut<'m, T> {
+	pub fn new(mat: &'m mut (impl MatTrait + ?Sized)) -> Result<Self> {
+		MatLayout::new::<T>(mat)
+			.map(|layout| Self { layout, pos: IterPos { row: 0, col: 0 }, _d: PhantomData })
+	}
+}
+
+impl<'m, T: DataType> Iterator for MatIterMut<'m, T> {
+	type Item = (Point, &'m mut T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.pos.next(&self.layout)
+			.map(|pt| (pt, unsafe { &mut *self.layout.elem_ptr::<T>(pt.y, pt.x) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.pos.remaining(&self.layout);
+		(len, Some(len))
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatIterMut<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatIterMut<'_, T> {}
+
+unsafe impl<T: DataType + Send> Send for MatIterMut<'_, T> {}
+
+/// Iterator over the rows of 2D `Mat` as slices, created by `MatTraitManual::iter_rows()`
+pub struct MatRowIter<'m, T> {
+	/// Rows that are not yet yielded, shrinks from both ends
+	layout: MatLayout,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T: DataType> MatRowIter<'m, T> {
+	pub fn new(mat: &'m (impl MatTrait + ?Sized)) -> Result<Self> {
+		MatLayout::new::<T>(mat)
+			.map(|layout| Self { layout, _d: PhantomData })
+	}
+}
+
+impl<'m, T: DataType> Iterator for MatRowIter<'m, T> {
+	type Item = &'m [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.layout.rows > 0 {
+			let (head, tail) = self.layout.split_at_row(1);
+			self.layout = tail;
+			Some(unsafe { slice::from_raw_parts(head.row_ptr::<T>(0), head.cols as usize) })
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.layout.rows as usize;
+		(len, Some(len))
+	}
+}
+
+impl<T: DataType> DoubleEndedIterator for MatRowIter<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.layout.rows > 0 {
+			self.layout.rows -= 1;
+			Some(unsafe { slice::from_raw_parts(self.layout.row_ptr::<T>(self.layout.rows), self.layout.cols as usize) })
+		} else {
+			None
+		}
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatRowIter<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatRowIter<'_, T> {}
+
+unsafe impl<T: DataType + Sync> Send for MatRowIter<'_, T> {}
+
+/// Iterator over the rows of 2D `Mat` as mutable slices, created by `MatTraitManual::iter_rows_mut()`
+pub struct MatRowIterMut<'m, T> {
+	/// Rows that are not yet yielded, shrinks from both ends
+	pub(super) layout: MatLayout,
+	pub(super) _d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T: DataType> MatRowIterMut<'m, T> {
+	pub fn new(mat: &'m mut (impl MatTrait + ?Sized)) -> Result<Self> {
+		MatLayout::new::<T>(mat)
+			.map(|layout| Self { layout, _d: PhantomData })
+	}
+}
+
+impl<'m, T: DataType> Iterator for MatRowIterMut<'m, T> {
+	type Item = &'m mut [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.layout.rows > 0 {
+			// rows never overlap because step is always at least the size of the row
+			let (head, tail) = self.layout.split_at_row(1);
+			self.layout = tail;
+			Some(unsafe { slice::from_raw_parts_mut(head.row_ptr::<T>(0), head.cols as usize) })
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.layout.rows as usize;
+		(len, Some(len))
+	}
+}
+
+impl<T: DataType> DoubleEndedIterator for MatRowIterMut<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.layout.rows > 0 {
+			self.layout.rows -= 1;
+			Some(unsafe { slice::from_raw_parts_mut(self.layout.row_ptr::<T>(self.layout.rows), self.layout.cols as usize) })
+		} else {
+			None
+		}
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatRowIterMut<'_, T> {}
+
+impl<T: DataType> FusedIterator for MatRowIterMut<'_, T> {}
+
+unsafe impl<T: DataType + Send> Send for MatRowIterMut<'_, T> {}
@@ -0,0 +1,157 @@
+//! Interoperability between `Mat` and `ndarray` arrays, enabled by the `ndarray` cargo feature
+
+use std::{
+	convert::TryInto,
+	mem,
+	ptr::NonNull,
+};
+
+use ndarray::{
+	ArrayBase,
+	ArrayView,
+	ArrayViewMut,
+	Data,
+	Dimension,
+	Ix3,
+	IxDyn,
+	ShapeBuilder,
+};
+
+use crate::{
+	core::{self, Mat, MatTrait},
+	Error,
+	Result,
+};
+
+use super::{DataType, match_dims, match_format};
+
+/// Shape, strides (in the units of `T`) and data pointer of the `Mat` in the form suitable for `ndarray`
+struct ArrayLayout<T> {
+	shape: Vec<usize>,
+	strides: Vec<usize>,
+	data: *mut T,
+}
+
+impl<T: DataType> ArrayLayout<T> {
+	/// Layout of the `Mat` with the dimensions taken from `MatSize`
+	///
+	/// When `channels` is `false` `T` must match the `Mat` element type exactly. Otherwise `T` must be a single-channel
+	/// type matching the `Mat` depth and a trailing axis of channels is added to the shape.
+	fn new(mat: &(impl MatTrait + ?Sized), dims: Option<usize>, channels: bool) -> Result<Self> {
+		let cn = if channels {
+			match_format::<T>(core::CV_MAKETYPE(mat.depth()?, 1))?;
+			Some(mat.channels()? as usize)
+		} else {
+			match_format::<T>(mat.typ()?)?;
+			None
+		};
+		let mut out = if mat.empty()? {
+			let dims = dims.unwrap_or(2);
+			Self { shape: vec![0; dims], strides: vec![0; dims], data: NonNull::dangling().as_ptr() }
+		} else {
+			if let Some(dims) = dims {
+				match_dims(mat, dims)?;
+			}
+			let elem_size1 = mat.elem_size1()?;
+			let type_size = mem::size_of::<T>();
+			let mat_size = mat.mat_size();
+			let mut shape = Vec::with_capacity(mat_size.len() + 1);
+			let mut strides = Vec::with_capacity(mat_size.len() + 1);
+			for (dim, &size) in mat_size.iter().enumerate() {
+				let step = mat.step1(dim as i32)? * elem_size1;
+				if step % type_size != 0 {
+					return Err(Error::new(core::StsBadArg, format!("Mat step: {} along dimension: {} is not a multiple of the element size: {}", step, dim, type_size)));
+				}
+				shape.push(size as usize);
+				strides.push(step / type_size);
+			}
+			Self { shape, strides, data: mat.data()? as *const u8 as *mut T }
+		};
+		if let Some(cn) = cn {
+			out.shape.push(cn);
+			out.strides.push(1);
+		}
+		Ok(out)
+	}
+
+	/// # Safety
+	/// Caller must ensure that the `Mat` data outlives `'a` and is not mutated during that time
+	unsafe fn view<'a, D: Dimension>(self) -> Result<ArrayView<'a, T, D>> {
+		ArrayView::<T, IxDyn>::from_shape_ptr(IxDyn(&self.shape).strides(IxDyn(&self.strides)), self.data)
+			.into_dimensionality()
+			.map_err(|e| Error::new(core::StsUnmatchedSizes, format!("Can't create array view: {}", e)))
+	}
+
+	/// # Safety
+	/// Caller must ensure that the `Mat` data outlives `'a` and is not aliased during that time
+	unsafe fn view_mut<'a, D: Dimension>(self) -> Result<ArrayViewMut<'a, T, D>> {
+		ArrayViewMut::<T, IxDyn>::from_shape_ptr(IxDyn(&self.shape).strides(IxDyn(&self.strides)), self.data)
+			.into_dimensionality()
+			.map_err(|e| Error::new(core::StsUnmatchedSizes, format!("Can't create array view: {}", e)))
+	}
+}
+
+pub(super) fn array_view<T: DataType, D: Dimension>(mat: &(impl MatTrait + ?Sized), dims: Option<usize>, channels: bool) -> Result<ArrayView<T, D>> {
+	ArrayLayout::<T>::new(mat, dims, channels)
+		.and_then(|layout| unsafe { layout.view() })
+}
+
+pub(super) fn array_view_mut<T: DataType, D: Dimension>(mat: &mut (impl MatTrait + ?Sized), dims: Option<usize>, channels: bool) -> Result<ArrayViewMut<T, D>> {
+	ArrayLayout::<T>::new(mat, dims, channels)
+		.and_then(|layout| unsafe { layout.view_mut() })
+}
+
+fn mat_sizes(shape: &[usize]) -> Result<Vec<i32>> {
+	let sizes = match shape.len() {
+		0 => vec![1, 1],
+		1 => vec![1, shape[0]],
+		_ => shape.to_vec(),
+	};
+	sizes.into_iter()
+		.map(|size| size.try_into().map_err(|_| Error::new(core::StsOutOfRange, format!("Array dimension: {} is too large for Mat", size))))
+		.collect()
+}
+
+impl Mat {
+	/// Create a new `Mat` copying the data from the `ndarray` array of any dimensionality
+	///
+	/// 0- and 1-dimensional arrays produce a single-row `Mat`, other arrays produce a `Mat` of the same dimensions.
+	pub fn from_array<S, D>(arr: &ArrayBase<S, D>) -> Result<Self>
+		where
+			S: Data,
+			S::Elem: DataType,
+			D: Dimension,
+	{
+		let sizes = mat_sizes(arr.shape())?;
+		let mut out = unsafe { Self::new_nd(sizes.len() as i32, &sizes[0], S::Elem::typ()) }?;
+		if !arr.is_empty() {
+			let mut view = array_view_mut::<S::Elem, IxDyn>(&mut out, None, false)?;
+			let src = arr.broadcast(view.raw_dim())
+				.ok_or_else(|| Error::new(core::StsUnmatchedSizes, format!("Can't convert array of shape: {:?} to Mat", arr.shape())))?;
+			view.assign(&src);
+		}
+		Ok(out)
+	}
+
+	/// Create a new 2D multichannel `Mat` copying the data from the 3-dimensional `ndarray` array, the last axis is
+	/// used as channels
+	///
+	/// The array element must be a single-channel type (like `u8` or `f32`), the number of channels must not exceed `CV_CN_MAX`.
+	pub fn from_array_channels<S>(arr: &ArrayBase<S, Ix3>) -> Result<Self>
+		where
+			S: Data,
+			S::Elem: DataType,
+	{
+		let (rows, cols, cn) = arr.dim();
+		if S::Elem::channels() != 1 || cn == 0 || cn > core::CV_CN_MAX as usize {
+			return Err(Error::new(core::StsBadArg, format!("Can't create Mat with {} channels of elements with {} channels", cn, S::Elem::channels())));
+		}
+		let sizes = mat_sizes(&[rows, cols])?;
+		let mut out = unsafe { Self::new_rows_cols(sizes[0], sizes[1], core::CV_MAKETYPE(S::Elem::depth(), cn as i32)) }?;
+		if !arr.is_empty() {
+			array_view_mut::<S::Elem, Ix3>(&mut out, Some(2), true)?
+				.assign(arr);
+		}
+		Ok(out)
+	}
+}
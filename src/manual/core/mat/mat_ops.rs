@@ -0,0 +1,183 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+	core::{self, Mat, MatExpr, MatExprTrait, Scalar},
+	Error,
+	Result,
+};
+
+/// Result of the arithmetic operator applied to `Mat`s and `MatExpr`s
+///
+/// Operators can't return `Result` and still be chained, so this type carries the possible error through the whole
+/// expression like `&a + &b * 0.5 - Scalar::all(1.)`. Call `into_result()` or `to_mat()` at the end to get the
+/// outcome of the expression.
+#[must_use]
+pub enum MatExprResult<T> {
+	Ok(T),
+	Err(Error),
+}
+
+impl<T> MatExprResult<T> {
+	#[inline]
+	pub fn into_result(self) -> Result<T> {
+		match self {
+			MatExprResult::Ok(v) => Ok(v),
+			MatExprResult::Err(e) => Err(e),
+		}
+	}
+}
+
+impl MatExprResult<MatExpr> {
+	/// Evaluate the expression into a new `Mat`
+	#[inline]
+	pub fn to_mat(self) -> Result<Mat> {
+		self.into_result()
+			.and_then(|expr| expr.to_mat())
+	}
+}
+
+impl<T> From<Result<T>> for MatExprResult<T> {
+	#[inline]
+	fn from(s: Result<T>) -> Self {
+		match s {
+			Ok(v) => MatExprResult::Ok(v),
+			Err(e) => MatExprResult::Err(e),
+		}
+	}
+}
+
+impl<T> From<MatExprResult<T>> for Result<T> {
+	#[inline]
+	fn from(s: MatExprResult<T>) -> Self {
+		s.into_result()
+	}
+}
+
+/// Operand of the arithmetic operator, unwraps the result of the previous operation in the chain
+trait Operand {
+	type Target;
+
+	fn into_target(self) -> Result<Self::Target>;
+}
+
+macro_rules! operand {
+	($($type: ty),+) => {
+		$(
+			impl Operand for $type {
+				type Target = Self;
+
+				#[inline]
+				fn into_target(self) -> Result<Self::Target> {
+					Ok(self)
+				}
+			}
+		)+
+	};
+}
+
+operand!(&Mat, MatExpr, &MatExpr, Scalar, f64);
+
+impl Operand for MatExprResult<MatExpr> {
+	type Target = MatExpr;
+
+	#[inline]
+	fn into_target(self) -> Result<Self::Target> {
+		self.into_result()
+	}
+}
+
+macro_rules! operand_arg {
+	(borrow $v: ident) => { &$v };
+	(copy $v: ident) => { $v };
+}
+
+macro_rules! binary_op {
+	($trait: ident, $method: ident, $func: ident, $lhs_kind: ident $($lhs: ty),+ ; $rhs_kind: ident $($rhs: ty),+) => {
+		binary_op!(@lhs $trait, $method, $func, $lhs_kind [$($lhs),+] $rhs_kind [$($rhs),+]);
+	};
+	(@lhs $trait: ident, $method: ident, $func: ident, $lhs_kind: ident [$($lhs: ty),+] $rhs_kind: ident $rhs: tt) => {
+		$(
+			binary_op!(@rhs $trait, $method, $func, $lhs_kind $lhs, $rhs_kind $rhs);
+		)+
+	};
+	(@rhs $trait: ident, $method: ident, $func: ident, $lhs_kind: ident $lhs: ty, $rhs_kind: ident [$($rhs: ty),+]) => {
+		$(
+			impl $trait<$rhs> for $lhs {
+				type Output = MatExprResult<MatExpr>;
+
+				#[inline]
+				fn $method(self, rhs: $rhs) -> Self::Output {
+					let lhs = match Operand::into_target(self) {
+						Ok(lhs) => lhs,
+						Err(e) => return MatExprResult::Err(e),
+					};
+					let rhs = match Operand::into_target(rhs) {
+						Ok(rhs) => rhs,
+						Err(e) => return MatExprResult::Err(e),
+					};
+					core::$func(operand_arg!($lhs_kind lhs), operand_arg!($rhs_kind rhs)).into()
+				}
+			}
+		)+
+	};
+}
+
+macro_rules! unary_op {
+	($trait: ident, $method: ident, $func: ident, $($type: ty),+) => {
+		$(
+			impl $trait for $type {
+				type Output = MatExprResult<MatExpr>;
+
+				#[inline]
+				fn $method(self) -> Self::Output {
+					match Operand::into_target(self) {
+						Ok(v) => core::$func(&v).into(),
+						Err(e) => MatExprResult::Err(e),
+					}
+				}
+			}
+		)+
+	};
+}
+
+// Mat operands are only accepted by reference to not consume them accidentally, MatExpr operands can be consumed
+// because they are usually temporary
+
+binary_op!(Add, add, add_mat_mat, borrow &Mat; borrow &Mat);
+binary_op!(Add, add, add_mat_matexpr, borrow &Mat; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Add, add, add_mat_scalar, borrow &Mat; copy Scalar);
+binary_op!(Add, add, add_matexpr_mat, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow &Mat);
+binary_op!(Add, add, add_matexpr_matexpr, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Add, add, add_matexpr_scalar, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; copy Scalar);
+binary_op!(Add, add, add_scalar_mat, copy Scalar; borrow &Mat);
+binary_op!(Add, add, add_scalar_matexpr, copy Scalar; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+
+binary_op!(Sub, sub, sub_mat_mat, borrow &Mat; borrow &Mat);
+binary_op!(Sub, sub, sub_mat_matexpr, borrow &Mat; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Sub, sub, sub_mat_scalar, borrow &Mat; copy Scalar);
+binary_op!(Sub, sub, sub_matexpr_mat, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow &Mat);
+binary_op!(Sub, sub, sub_matexpr_matexpr, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Sub, sub, sub_matexpr_scalar, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; copy Scalar);
+binary_op!(Sub, sub, sub_scalar_mat, copy Scalar; borrow &Mat);
+binary_op!(Sub, sub, sub_scalar_matexpr, copy Scalar; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+
+binary_op!(Mul, mul, mul_mat_mat, borrow &Mat; borrow &Mat);
+binary_op!(Mul, mul, mul_mat_matexpr, borrow &Mat; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Mul, mul, mul_mat_f64, borrow &Mat; copy f64);
+binary_op!(Mul, mul, mul_matexpr_mat, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow &Mat);
+binary_op!(Mul, mul, mul_matexpr_matexpr, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Mul, mul, mul_matexpr_f64, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; copy f64);
+binary_op!(Mul, mul, mul_f64_mat, copy f64; borrow &Mat);
+binary_op!(Mul, mul, mul_f64_matexpr, copy f64; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+
+binary_op!(Div, div, div_mat_mat, borrow &Mat; borrow &Mat);
+binary_op!(Div, div, div_mat_matexpr, borrow &Mat; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Div, div, div_mat_f64, borrow &Mat; copy f64);
+binary_op!(Div, div, div_matexpr_mat, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow &Mat);
+binary_op!(Div, div, div_matexpr_matexpr, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+binary_op!(Div, div, div_matexpr_f64, borrow MatExpr, &MatExpr, MatExprResult<MatExpr>; copy f64);
+binary_op!(Div, div, div_f64_mat, copy f64; borrow &Mat);
+binary_op!(Div, div, div_f64_matexpr, copy f64; borrow MatExpr, &MatExpr, MatExprResult<MatExpr>);
+
+unary_op!(Neg, neg, sub_mat, &Mat);
+unary_op!(Neg, neg, sub_matexpr, MatExpr, &MatExpr, MatExprResult<MatExpr>);
@@ -0,0 +1,185 @@
+//! Parallel processing of the `Mat` rows with `rayon`, enabled by the `rayon` cargo feature
+
+use std::marker::PhantomData;
+
+use rayon::iter::{
+	IndexedParallelIterator,
+	ParallelIterator,
+	plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer},
+};
+
+use crate::{
+	core::{self, MatTrait},
+	Error,
+	Result,
+};
+
+use super::{DataType, mat_iter::MatLayout, MatRowIterMut};
+
+impl<'m, T: DataType + Send> Producer for MatRowIterMut<'m, T> {
+	type Item = &'m mut [T];
+	type IntoIter = Self;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let (head, tail) = self.layout.split_at_row(index as i32);
+		(MatRowIterMut { layout: head, _d: PhantomData }, MatRowIterMut { layout: tail, _d: PhantomData })
+	}
+}
+
+/// Parallel iterator over the rows of 2D `Mat` as mutable slices, created by `MatTraitManual::par_rows_mut()`
+///
+/// Works for non-continuous `Mat`s (e.g. ROIs) too, use `enumerate()` to get the row index.
+pub struct MatParRowsMut<'m, T> {
+	rows: MatRowIterMut<'m, T>,
+}
+
+impl<'m, T: DataType + Send> MatParRowsMut<'m, T> {
+	pub fn new(mat: &'m mut (impl MatTrait + ?Sized)) -> Result<Self> {
+		MatRowIterMut::new(mat)
+			.map(|rows| Self { rows })
+	}
+}
+
+impl<'m, T: DataType + Send> ParallelIterator for MatParRowsMut<'m, T> {
+	type Item = &'m mut [T];
+
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.rows.len())
+	}
+}
+
+impl<T: DataType + Send> IndexedParallelIterator for MatParRowsMut<'_, T> {
+	fn len(&self) -> usize {
+		self.rows.len()
+	}
+
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(self.rows)
+	}
+}
+
+/// Parallel iterator over the non-overlapping bands of `Mat` rows, created by `MatTraitManual::par_chunks_mut()`
+///
+/// Each band is yielded as a `MatRowIterMut`, the last band can have fewer rows than requested.
+pub struct MatParChunksMut<'m, T> {
+	chunks: MatRowChunksMut<'m, T>,
+}
+
+impl<'m, T: DataType + Send> MatParChunksMut<'m, T> {
+	pub fn new(mat: &'m mut (impl MatTrait + ?Sized), rows_per_chunk: i32) -> Result<Self> {
+		if rows_per_chunk <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid number of rows per chunk: {}, it must be positive", rows_per_chunk)));
+		}
+		MatLayout::new::<T>(mat)
+			.map(|layout| Self { chunks: MatRowChunksMut { layout, rows_per_chunk, _d: PhantomData } })
+	}
+}
+
+impl<'m, T: DataType + Send> ParallelIterator for MatParChunksMut<'m, T> {
+	type Item = MatRowIterMut<'m, T>;
+
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.chunks.len())
+	}
+}
+
+impl<T: DataType + Send> IndexedParallelIterator for MatParChunksMut<'_, T> {
+	fn len(&self) -> usize {
+		self.chunks.len()
+	}
+
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(self.chunks)
+	}
+}
+
+/// Sequential iterator over the bands of rows, serves as a `Producer` for `MatParChunksMut`
+struct MatRowChunksMut<'m, T> {
+	/// Rows that are not yet yielded, shrinks from both ends
+	layout: MatLayout,
+	rows_per_chunk: i32,
+	_d: PhantomData<&'m mut T>,
+}
+
+unsafe impl<T: DataType + Send> Send for MatRowChunksMut<'_, T> {}
+
+impl<'m, T: DataType> Iterator for MatRowChunksMut<'m, T> {
+	type Item = MatRowIterMut<'m, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.layout.rows > 0 {
+			let (head, tail) = self.layout.split_at_row(self.rows_per_chunk.min(self.layout.rows));
+			self.layout = tail;
+			Some(MatRowIterMut { layout: head, _d: PhantomData })
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let rows = self.layout.rows as usize;
+		let rows_per_chunk = self.rows_per_chunk as usize;
+		let len = (rows + rows_per_chunk - 1) / rows_per_chunk;
+		(len, Some(len))
+	}
+}
+
+impl<T: DataType> DoubleEndedIterator for MatRowChunksMut<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.layout.rows > 0 {
+			let last_chunk_rows = match self.layout.rows % self.rows_per_chunk {
+				0 => self.rows_per_chunk,
+				rem => rem,
+			};
+			let (head, tail) = self.layout.split_at_row(self.layout.rows - last_chunk_rows);
+			self.layout = head;
+			Some(MatRowIterMut { layout: tail, _d: PhantomData })
+		} else {
+			None
+		}
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for MatRowChunksMut<'_, T> {}
+
+impl<'m, T: DataType + Send> Producer for MatRowChunksMut<'m, T> {
+	type Item = MatRowIterMut<'m, T>;
+	type IntoIter = Self;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let rows = (index as i32 * self.rows_per_chunk).min(self.layout.rows);
+		let (head, tail) = self.layout.split_at_row(rows);
+		(Self { layout: head, ..self }, Self { layout: tail, ..self })
+	}
+}
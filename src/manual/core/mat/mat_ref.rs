@@ -0,0 +1,159 @@
+use std::{
+	ffi::c_void,
+	fmt,
+	marker::PhantomData,
+};
+
+use crate::{
+	core::{
+		_InputArray,
+		_InputOutputArray,
+		_OutputArray,
+		Mat,
+		Rect,
+		ToInputArray,
+		ToInputOutputArray,
+		ToOutputArray,
+	},
+	prelude::*,
+	Result,
+};
+
+/// Read-only view into the data of another `Mat` (e.g. a region of interest)
+///
+/// The view shares the buffer with its parent and keeps the parent borrowed for its whole lifetime, so the
+/// parent can't be reallocated (e.g. with `create()` or by passing it as an output array) while the view is
+/// alive. Use `MatTraitManual::roi_ref()`, `rowscols_ref()` or `ranges_ref()` to create one. Views of the
+/// borrowed slices are `BorrowedMat`.
+///
+/// Several read-only views of the same `Mat` can exist at the same time, that's why the view doesn't implement
+/// `MatTrait` and only provides the methods that return borrowed data. It can still be passed as an input array to
+/// any OpenCV function, use `to_mat()` to get an independent copy.
+pub struct MatRef<'m> {
+	inner: Mat,
+	_d: PhantomData<&'m Mat>,
+}
+
+impl<'m> MatRef<'m> {
+	/// # Safety
+	/// Caller must ensure that the data referenced by `inner` stays valid and is not mutated for the lifetime `'m`
+	#[inline]
+	pub(crate) unsafe fn new(inner: Mat) -> Self {
+		Self { inner, _d: PhantomData }
+	}
+
+	/// Underlying header for the crate internal read-only access, must not be used to create the mutable aliases
+	#[inline]
+	pub(crate) fn as_mat(&self) -> &Mat {
+		&self.inner
+	}
+
+	mat_view_forward!();
+
+	/// Read-only view of the `roi` region of the same data
+	pub fn roi(&self, roi: Rect) -> Result<MatRef<'m>> {
+		Mat::roi(&self.inner, roi)
+			.map(|mat| unsafe { MatRef::new(mat) })
+	}
+}
+
+impl ToInputArray for MatRef<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl ToInputArray for &MatRef<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl fmt::Debug for MatRef<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
+
+/// Mutable view into the data of another `Mat` (e.g. a region of interest)
+///
+/// The view shares the buffer with its parent and keeps the parent mutably borrowed for its whole lifetime,
+/// so it's not possible to obtain two mutable views into the same `Mat` at the same time. Use
+/// `MatTraitManual::split_at_row_mut()` or `split_at_col_mut()` to get several non-overlapping mutable views.
+pub struct MatRefMut<'m> {
+	inner: Mat,
+	_d: PhantomData<&'m mut Mat>,
+}
+
+impl<'m> MatRefMut<'m> {
+	/// # Safety
+	/// Caller must ensure that the data referenced by `inner` stays valid and is not aliased for the lifetime `'m`
+	#[inline]
+	pub(crate) unsafe fn new(inner: Mat) -> Self {
+		Self { inner, _d: PhantomData }
+	}
+
+	/// Copy the data of the view into a new independent `Mat`
+	#[inline]
+	pub fn to_mat(&self) -> Result<Mat> {
+		self.inner.try_clone()
+	}
+}
+
+impl MatTrait for MatRefMut<'_> {
+	#[inline]
+	fn as_raw_Mat(&self) -> *const c_void { self.inner.as_raw_Mat() }
+
+	#[inline]
+	fn as_raw_mut_Mat(&mut self) -> *mut c_void { self.inner.as_raw_mut_Mat() }
+}
+
+impl ToInputArray for MatRefMut<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl ToInputArray for &MatRefMut<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl ToOutputArray for MatRefMut<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		_OutputArray::from_mat_mut(&mut self.inner)
+	}
+}
+
+impl ToOutputArray for &mut MatRefMut<'_> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		(*self).output_array()
+	}
+}
+
+impl ToInputOutputArray for MatRefMut<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		_InputOutputArray::from_mat_mut(&mut self.inner)
+	}
+}
+
+impl ToInputOutputArray for &mut MatRefMut<'_> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		(*self).input_output_array()
+	}
+}
+
+impl fmt::Debug for MatRefMut<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
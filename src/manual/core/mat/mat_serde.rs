@@ -0,0 +1,156 @@
+//! `Serialize` and `Deserialize` implementations for `Mat`, enabled by the `serde` cargo feature
+
+use std::{
+	borrow::Cow,
+	fmt,
+	slice,
+};
+
+use serde::{
+	de::{self, SeqAccess, Visitor},
+	Deserialize,
+	Deserializer,
+	ser,
+	Serialize,
+	Serializer,
+};
+
+use crate::{
+	core::{self, Mat, MatTrait},
+	Error,
+	Result,
+};
+
+use super::{match_dims_2d, MatTraitManual};
+
+/// Raw bytes of the `Mat` data, serialized as a byte array to have a compact representation in binary formats
+pub(crate) struct Bytes<'a>(pub(crate) Cow<'a, [u8]>);
+
+impl Serialize for Bytes<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(&self.0)
+	}
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+	type Value = Vec<u8>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a byte array")
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		Ok(v.to_vec())
+	}
+
+	fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+		Ok(v)
+	}
+
+	// self-describing formats like JSON store bytes as a sequence of numbers
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		// the size hint comes from the untrusted input, so it's not used for preallocation
+		let mut out = Vec::new();
+		while let Some(x) = seq.next_element()? {
+			out.push(x);
+		}
+		Ok(out)
+	}
+}
+
+impl<'de> Deserialize<'de> for Bytes<'_> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_byte_buf(BytesVisitor)
+			.map(|v| Bytes(Cow::Owned(v)))
+	}
+}
+
+/// Size of the element of the `Mat` type in bytes, same as `CV_ELEM_SIZE()`, `None` if the type is invalid
+fn elem_size(typ: i32) -> Option<usize> {
+	if typ & !core::CV_MAT_TYPE_MASK != 0 {
+		return None;
+	}
+	let depth_size = match core::CV_MAT_DEPTH(typ) {
+		core::CV_8U | core::CV_8S => 1,
+		core::CV_16U | core::CV_16S => 2,
+		#[cfg(feature = "opencv-4")]
+		core::CV_16F => 2,
+		core::CV_32S | core::CV_32F => 4,
+		core::CV_64F => 8,
+		_ => return None,
+	};
+	let channels = ((typ & core::CV_MAT_CN_MASK) >> core::CV_CN_SHIFT) + 1;
+	Some(depth_size * channels as usize)
+}
+
+#[derive(Serialize, Deserialize)]
+// must match MAT_STRUCT in file_storage_serde.rs
+#[serde(rename = "$__opencv_private_Mat")]
+pub(crate) struct MatData<'a> {
+	pub(crate) rows: i32,
+	pub(crate) cols: i32,
+	#[serde(rename = "type")]
+	pub(crate) typ: i32,
+	pub(crate) data: Bytes<'a>,
+}
+
+impl<'a> MatData<'a> {
+	pub(crate) fn new(mat: &'a Mat) -> Result<Self> {
+		match_dims_2d(mat)?;
+		let data = if mat.empty()? {
+			Cow::Borrowed(&[][..])
+		} else if mat.is_continuous()? {
+			let len = mat.total()? * mat.elem_size()?;
+			Cow::Borrowed(unsafe { slice::from_raw_parts(mat.data()? as *const u8, len) })
+		} else {
+			// cloned Mat is always continuous
+			let mat = mat.try_clone()?;
+			let len = mat.total()? * mat.elem_size()?;
+			Cow::Owned(unsafe { slice::from_raw_parts(mat.data()? as *const u8, len) }.to_vec())
+		};
+		Ok(Self { rows: mat.rows(), cols: mat.cols(), typ: mat.typ()?, data: Bytes(data) })
+	}
+
+	/// Creates the `Mat` from the deserialized data, everything is validated before the allocation because the input
+	/// is not trusted
+	pub(crate) fn into_mat(self) -> Result<Mat> {
+		if self.rows < 0 || self.cols < 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid Mat dimensions: {}x{}", self.rows, self.cols)));
+		}
+		let elem_size = elem_size(self.typ)
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Invalid Mat type: {}", self.typ)))?;
+		let len = (self.rows as usize).checked_mul(self.cols as usize)
+			.and_then(|total| total.checked_mul(elem_size))
+			.ok_or_else(|| Error::new(core::StsOutOfRange, format!("Mat of {}x{} elements is too large", self.rows, self.cols)))?;
+		if self.data.0.len() != len {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Mat data length: {} doesn't match the expected length: {}", self.data.0.len(), len)));
+		}
+		let mut out = unsafe { Mat::new_rows_cols(self.rows, self.cols, self.typ) }?;
+		if len > 0 {
+			unsafe { slice::from_raw_parts_mut(out.data_mut() as *mut u8, len) }.copy_from_slice(&self.data.0);
+		}
+		Ok(out)
+	}
+}
+
+/// Serialized as a struct with `rows`, `cols`, `type` and `data` fields, `data` contains the raw bytes of the `Mat`
+/// elements in the row-major order
+///
+/// Only 2-dimensional `Mat`s are supported.
+impl Serialize for Mat {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		MatData::new(self)
+			.map_err(ser::Error::custom)?
+			.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Mat {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		MatData::deserialize(deserializer)?
+			.into_mat()
+			.map_err(de::Error::custom)
+	}
+}
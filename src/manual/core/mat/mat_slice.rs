@@ -0,0 +1,119 @@
+use std::ops::{self, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::{
+	core::{self, MatTrait, Range, Vector},
+	Error,
+	Result,
+};
+
+/// Selection along a single dimension of the `Mat`, usually created by the `s!` macro
+///
+/// Unlike NumPy a single index doesn't remove the dimension, the result keeps it with the size of 1 because `Mat` can't
+/// have less than 2 dimensions anyway.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SliceArg {
+	/// `..`, the whole dimension
+	All,
+	/// `start..end`, `start..`, `..end` and the inclusive variants, `None` means the corresponding boundary of the dimension
+	Range(Option<i32>, Option<i32>),
+	/// `i`, a single element along the dimension
+	Index(i32),
+}
+
+impl SliceArg {
+	/// Convert to `Range` for the dimension of the specified size, fails if the selection is out of bounds
+	pub fn to_range(self, dim: usize, size: i32) -> Result<Range> {
+		let (start, end) = match self {
+			SliceArg::All => return Range::all(),
+			SliceArg::Range(start, end) => (start.unwrap_or(0), end.unwrap_or(size)),
+			SliceArg::Index(i) => (i, i.saturating_add(1)),
+		};
+		if 0 <= start && start <= end && end <= size {
+			Range::new(start, end)
+		} else {
+			Err(Error::new(core::StsOutOfRange, format!("Slice: {}..{} along dimension: {} out of bounds 0..{}", start, end, dim, size)))
+		}
+	}
+}
+
+impl From<RangeFull> for SliceArg {
+	#[inline]
+	fn from(_: RangeFull) -> Self {
+		SliceArg::All
+	}
+}
+
+impl From<ops::Range<i32>> for SliceArg {
+	#[inline]
+	fn from(r: ops::Range<i32>) -> Self {
+		SliceArg::Range(Some(r.start), Some(r.end))
+	}
+}
+
+impl From<RangeFrom<i32>> for SliceArg {
+	#[inline]
+	fn from(r: RangeFrom<i32>) -> Self {
+		SliceArg::Range(Some(r.start), None)
+	}
+}
+
+impl From<RangeTo<i32>> for SliceArg {
+	#[inline]
+	fn from(r: RangeTo<i32>) -> Self {
+		SliceArg::Range(None, Some(r.end))
+	}
+}
+
+impl From<RangeInclusive<i32>> for SliceArg {
+	#[inline]
+	fn from(r: RangeInclusive<i32>) -> Self {
+		SliceArg::Range(Some(*r.start()), Some(r.end().saturating_add(1)))
+	}
+}
+
+impl From<RangeToInclusive<i32>> for SliceArg {
+	#[inline]
+	fn from(r: RangeToInclusive<i32>) -> Self {
+		SliceArg::Range(None, Some(r.end.saturating_add(1)))
+	}
+}
+
+impl From<i32> for SliceArg {
+	#[inline]
+	fn from(i: i32) -> Self {
+		SliceArg::Index(i)
+	}
+}
+
+/// Build the array of `SliceArg`s for `MatTraitManual::slice()`, one argument per dimension of the `Mat`
+///
+/// Each argument can be a range (`a..b`, `a..`, `..b`, `a..=b`, `..=b`), `..` to select the whole dimension or a single index.
+///
+/// ```no_run
+/// # use opencv::{core, prelude::*, s};
+/// # fn main() -> opencv::Result<()> {
+/// let mat = Mat::new_nd_with_default(&[4, 6, 3], core::CV_8U, core::Scalar::all(0.))?;
+/// let part = mat.slice(&s![.., 2..5, 1])?;
+/// assert_eq!(&[4, 3, 1], part.shape());
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! s {
+	($($arg: expr),* $(,)?) => {
+		[$($crate::core::SliceArg::from($arg)),*]
+	};
+}
+
+/// Converts `args` to the `Range`s suitable for `Mat` constructor validating them against the `Mat` shape
+pub(super) fn slice_ranges(mat: &(impl MatTrait + ?Sized), args: &[SliceArg]) -> Result<Vector<Range>> {
+	let shape = mat.mat_size();
+	if args.len() != shape.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Mat dims is: {}, but slice dims is: {}", shape.len(), args.len())));
+	}
+	args.iter()
+		.zip(shape.iter())
+		.enumerate()
+		.map(|(dim, (arg, &size))| arg.to_range(dim, size))
+		.collect()
+}
@@ -0,0 +1,255 @@
+use std::{
+	convert::TryFrom,
+	ffi::c_void,
+	fmt,
+	marker::PhantomData,
+	ptr,
+	slice,
+};
+
+use crate::{
+	core::{self, Mat, SparseMat, SparseMatConstIterator, SparseMatConstIteratorTrait, SparseMatTrait},
+	Error,
+	Result,
+	sys,
+};
+
+use super::{DataType, match_format};
+
+/// [docs.opencv.org](https://docs.opencv.org/master/d7/d6a/classcv_1_1SparseMat__.html)
+///
+/// Typed wrapper around `SparseMat` that gives safe access to the elements by their indices. Only non-zero elements
+/// are stored, accessing the missing one returns `None` instead of creating it. This struct is freely convertible
+/// into and from `SparseMat` using `into` and `try_from` methods.
+pub struct SparseMat_<T> {
+	inner: SparseMat,
+	_type: PhantomData<T>,
+}
+
+impl<T: DataType> TryFrom<SparseMat> for SparseMat_<T> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: SparseMat) -> Result<Self, Self::Error> {
+		match_format::<T>(mat.typ()?)
+			.map(|_| Self { inner: mat, _type: PhantomData })
+	}
+}
+
+impl<T: DataType> From<SparseMat_<T>> for SparseMat {
+	#[inline]
+	fn from(s: SparseMat_<T>) -> Self {
+		s.inner
+	}
+}
+
+impl<T: DataType> SparseMat_<T> {
+	/// Create a new empty `SparseMat_` with the specified size along each dimension
+	pub fn new(sizes: &[i32]) -> Result<Self> {
+		if sizes.is_empty() {
+			return Err(Error::new(core::StsBadArg, "SparseMat must have at least 1 dimension".to_string()));
+		}
+		SparseMat::new(sizes.len() as i32, &sizes[0], T::typ())
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Create a new `SparseMat_` with the specified size from the iterator of `(indices, value)` pairs
+	///
+	/// Later values overwrite the earlier ones with the same indices. Fails if any of the indices is out of bounds.
+	pub fn from_nodes<I: AsRef<[i32]>>(sizes: &[i32], nodes: impl IntoIterator<Item=(I, T)>) -> Result<Self> {
+		let mut out = Self::new(sizes)?;
+		for (idx, val) in nodes {
+			out.insert(idx.as_ref(), val)?;
+		}
+		Ok(out)
+	}
+
+	/// Create a new `SparseMat_` from the non-zero elements of the dense `Mat`
+	#[inline]
+	pub fn from_mat(mat: &Mat) -> Result<Self> {
+		match_format::<T>(mat.typ()?)?;
+		SparseMat::from_mat(mat)
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	/// Convert to the dense `Mat`, missing elements are filled with zeros
+	#[inline]
+	pub fn to_mat(&self) -> Result<Mat> {
+		let mut out = Mat::default()?;
+		self.copy_to_mat(&mut out)?;
+		Ok(out)
+	}
+
+	/// # Safety
+	/// Caller must ensure that the type of `mat` matches `T`
+	#[inline]
+	unsafe fn from_untyped_unchecked(mat: SparseMat) -> Self {
+		Self { inner: mat, _type: PhantomData }
+	}
+
+	#[inline]
+	pub fn into_untyped(self) -> SparseMat {
+		self.into()
+	}
+
+	#[inline]
+	pub fn as_untyped(&self) -> &SparseMat {
+		&self.inner
+	}
+
+	/// Size along each dimension
+	pub fn sizes(&self) -> Result<&[i32]> {
+		let dims = self.dims()? as usize;
+		self.size()
+			.map(|size| unsafe { slice::from_raw_parts(size, dims) })
+	}
+
+	/// Number of stored (non-zero) elements, same as `nzcount()`
+	#[inline]
+	pub fn len(&self) -> Result<usize> {
+		self.nzcount()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> Result<bool> {
+		self.len().map(|len| len == 0)
+	}
+
+	/// Element at the specified indices, `None` if it's not stored or the indices are out of bounds
+	pub fn get(&self, idx: &[i32]) -> Option<&T> {
+		extern "C" { fn cv_manual_SparseMat_find(instance: *const c_void, idx: *const i32) -> sys::Result<*const u8>; }
+		self.match_indices(idx).ok()?;
+		let ptr = unsafe { cv_manual_SparseMat_find(self.as_raw_SparseMat(), idx.as_ptr()) }.into_result().ok()?;
+		unsafe { (ptr as *const T).as_ref() }
+	}
+
+	/// Like `get()`, but returns a mutable reference
+	pub fn get_mut(&mut self, idx: &[i32]) -> Option<&mut T> {
+		extern "C" { fn cv_manual_SparseMat_find(instance: *const c_void, idx: *const i32) -> sys::Result<*const u8>; }
+		self.match_indices(idx).ok()?;
+		let ptr = unsafe { cv_manual_SparseMat_find(self.as_raw_SparseMat(), idx.as_ptr()) }.into_result().ok()?;
+		unsafe { (ptr as *mut T).as_mut() }
+	}
+
+	/// Store the element at the specified indices returning the previously stored one
+	pub fn insert(&mut self, idx: &[i32], val: T) -> Result<Option<T>> {
+		extern "C" { fn cv_manual_SparseMat_ptr_create(instance: *mut c_void, idx: *const i32) -> sys::Result<*mut u8>; }
+		self.match_indices(idx)?;
+		let prev = self.get(idx).copied();
+		let ptr = unsafe { cv_manual_SparseMat_ptr_create(self.as_raw_mut_SparseMat(), idx.as_ptr()) }.into_result()?;
+		let elem = unsafe { (ptr as *mut T).as_mut() }
+			.ok_or_else(|| Error::new(core::StsNullPtr, "Can't create SparseMat element".to_string()))?;
+		*elem = val;
+		Ok(prev)
+	}
+
+	/// Remove the element at the specified indices returning it, `None` if it wasn't stored
+	pub fn remove(&mut self, idx: &[i32]) -> Result<Option<T>> {
+		extern "C" { fn cv_manual_SparseMat_erase(instance: *mut c_void, idx: *const i32) -> sys::Result_void; }
+		self.match_indices(idx)?;
+		let prev = self.get(idx).copied();
+		if prev.is_some() {
+			unsafe { cv_manual_SparseMat_erase(self.as_raw_mut_SparseMat(), idx.as_ptr()) }.into_result()?;
+		}
+		Ok(prev)
+	}
+
+	/// Iterate over the stored elements yielding `(indices, value)` pairs, the order is unspecified
+	pub fn iter(&self) -> Result<SparseMatIter<T>> {
+		Ok(SparseMatIter {
+			iter: self.begin()?,
+			dims: self.dims()? as usize,
+			remaining: self.nzcount()?,
+			_type: PhantomData,
+		})
+	}
+
+	/// Create a full copy of the matrix and the underlying data
+	#[inline]
+	pub fn try_clone(&self) -> Result<Self> {
+		self.inner.try_clone()
+			.map(|mat| unsafe { Self::from_untyped_unchecked(mat) })
+	}
+
+	fn match_indices(&self, idx: &[i32]) -> Result<()> {
+		let sizes = self.sizes()?;
+		if idx.len() != sizes.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("SparseMat dims is: {}, but requested dims is: {}", sizes.len(), idx.len())));
+		}
+		let mut out_of_bounds = sizes.iter()
+			.enumerate()
+			.filter(|&(i, &x)| idx[i] < 0 || idx[i] >= x);
+		if let Some((out_dim, out_size)) = out_of_bounds.next() {
+			Err(Error::new(core::StsOutOfRange, format!("Index: {} along dimension: {} out of bounds 0..{}", idx[out_dim], out_dim, out_size)))
+		} else {
+			Ok(())
+		}
+	}
+}
+
+impl<T: DataType> Clone for SparseMat_<T> {
+	#[inline]
+	/// Calls try_clone() and panics if that fails
+	fn clone(&self) -> Self {
+		self.try_clone().expect("Cannot clone SparseMat_")
+	}
+}
+
+impl<'m, T: DataType> IntoIterator for &'m SparseMat_<T> {
+	type Item = (&'m [i32], &'m T);
+	type IntoIter = SparseMatIter<'m, T>;
+
+	/// Panics if the iterator can't be created, use `iter()` to handle the error
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter().expect("Cannot iterate over SparseMat_")
+	}
+}
+
+impl<T> SparseMatTrait for SparseMat_<T> {
+	#[inline]
+	fn as_raw_SparseMat(&self) -> *const c_void { self.inner.as_raw_SparseMat() }
+
+	#[inline]
+	fn as_raw_mut_SparseMat(&mut self) -> *mut c_void { self.inner.as_raw_mut_SparseMat() }
+}
+
+impl<T: DataType> fmt::Debug for SparseMat_<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("SparseMat_")
+			.field("type", &self.typ().map_err(|_| fmt::Error)?)
+			.field("sizes", &self.sizes().map_err(|_| fmt::Error)?)
+			.field("nzcount", &self.nzcount().map_err(|_| fmt::Error)?)
+			.finish()
+	}
+}
+
+/// Iterator over the stored elements of `SparseMat_`, created by `SparseMat_::iter()`
+pub struct SparseMatIter<'m, T> {
+	iter: SparseMatConstIterator,
+	dims: usize,
+	remaining: usize,
+	_type: PhantomData<&'m T>,
+}
+
+impl<'m, T: DataType> Iterator for SparseMatIter<'m, T> {
+	type Item = (&'m [i32], &'m T);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		extern "C" { fn cv_manual_SparseMatConstIterator_next(instance: *mut c_void, idx: *mut *const i32, value: *mut *const u8) -> bool; }
+		let mut idx = ptr::null();
+		let mut value = ptr::null();
+		if unsafe { cv_manual_SparseMatConstIterator_next(self.iter.as_raw_mut_SparseMatConstIterator(), &mut idx, &mut value) } {
+			self.remaining = self.remaining.saturating_sub(1);
+			Some(unsafe { (slice::from_raw_parts(idx, self.dims), &*(value as *const T)) })
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<T: DataType> ExactSizeIterator for SparseMatIter<'_, T> {}
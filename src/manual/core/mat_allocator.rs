@@ -0,0 +1,118 @@
+use std::{
+	ffi::c_void,
+	mem,
+	panic::{self, AssertUnwindSafe},
+	ptr,
+};
+
+use crate::{
+	core::{self, DataType, Mat},
+	platform_types::size_t,
+	Result,
+	sys,
+	traits::Boxed,
+};
+
+use super::mat::match_slice_layout;
+
+/// Allocator for the `Mat` data implemented in Rust, counterpart of C++ `cv::MatAllocator`
+///
+/// Only the host memory part of the C++ interface is exposed, the rest (like the `UMat` mapping) uses the
+/// OpenCV defaults. The allocator can be called from any thread that OpenCV uses internally.
+///
+/// Register the allocator with `MatAllocatorHandle::new()` and then install it for a specific `Mat` with
+/// `MatTraitManual::set_allocator()` or globally with `Mat::set_default_allocator()`.
+pub trait MatAllocator: Send + Sync {
+	/// Allocate the buffer of `size` bytes, returns null pointer on failure
+	///
+	/// The returned buffer should be suitably aligned for any `DataType`, OpenCV itself uses 64-byte alignment.
+	fn allocate(&self, size: usize) -> *mut u8;
+
+	/// Free the buffer previously returned by `allocate()`, a panic here is caught and the buffer is leaked
+	/// # Safety
+	/// `data` and `size` are exactly the values that were previously passed to and returned from `allocate()`
+	unsafe fn deallocate(&self, data: *mut u8, size: usize);
+}
+
+/// Handle to the `MatAllocator` registered with OpenCV
+///
+/// Registered allocators are never freed because any `Mat` allocated with them can outlive the place where they
+/// were installed, in the same way the callbacks are leaked.
+#[derive(Copy, Clone, Debug)]
+pub struct MatAllocatorHandle {
+	ptr: *mut c_void,
+}
+
+unsafe impl Send for MatAllocatorHandle {}
+
+unsafe impl Sync for MatAllocatorHandle {}
+
+impl MatAllocatorHandle {
+	/// Register the `allocator` with OpenCV
+	pub fn new(allocator: impl MatAllocator + 'static) -> Result<Self> {
+		extern "C" { fn cv_manual_RustMatAllocator_new(allocator: *mut c_void) -> sys::Result<*mut c_void>; }
+		let allocator: Box<Box<dyn MatAllocator>> = Box::new(Box::new(allocator));
+		let allocator = Box::into_raw(allocator);
+		unsafe { cv_manual_RustMatAllocator_new(allocator as _) }
+			.into_result()
+			.map(|ptr| Self { ptr })
+			.map_err(|e| {
+				drop(unsafe { Box::from_raw(allocator) });
+				e
+			})
+	}
+
+	#[inline]
+	pub fn as_raw(&self) -> *mut c_void {
+		self.ptr
+	}
+}
+
+impl Mat {
+	/// Create a new `Mat` that takes ownership of the `Vec` data without copying it
+	///
+	/// The `Vec` is freed using the Rust allocator when the last `Mat` referencing its data is dropped.
+	pub fn from_vec<T: DataType + Send + 'static>(mut v: Vec<T>, rows: i32, cols: i32) -> Result<Self> {
+		extern "C" { fn cv_manual_Mat_from_rust_buffer(rows: i32, cols: i32, typ: i32, data: *mut c_void, size: size_t, owner: *mut c_void) -> sys::Result<*mut c_void>; }
+		match_slice_layout::<T>(rows, cols, v.len(), core::Mat_AUTO_STEP)?;
+		if rows == 0 || cols == 0 {
+			return unsafe { Self::new_rows_cols(rows, cols, T::typ()) };
+		}
+		let data = v.as_mut_ptr() as *mut c_void;
+		let size = v.len() * mem::size_of::<T>();
+		let owner: Box<Box<dyn Send>> = Box::new(Box::new(v));
+		let owner = Box::into_raw(owner);
+		unsafe { cv_manual_Mat_from_rust_buffer(rows, cols, T::typ(), data, size, owner as _) }
+			.into_result()
+			.map(|ptr| unsafe { Self::from_raw(ptr) })
+			.map_err(|e| {
+				drop(unsafe { Box::from_raw(owner) });
+				e
+			})
+	}
+
+	/// Set the allocator used for all `Mat`s that don't have their own allocator set, pass `None` to
+	/// restore the OpenCV standard allocator
+	pub fn set_default_allocator(allocator: Option<MatAllocatorHandle>) {
+		extern "C" { fn cv_manual_Mat_set_default_allocator(allocator: *mut c_void); }
+		unsafe { cv_manual_Mat_set_default_allocator(allocator.map_or(ptr::null_mut(), |a| a.as_raw())) }
+	}
+}
+
+#[no_mangle]
+unsafe extern "C" fn ocvrs_mat_allocator_allocate(allocator: *mut c_void, size: size_t) -> *mut u8 {
+	let allocator = &*(allocator as *const Box<dyn MatAllocator>);
+	panic::catch_unwind(AssertUnwindSafe(|| allocator.allocate(size)))
+		.unwrap_or(ptr::null_mut())
+}
+
+#[no_mangle]
+unsafe extern "C" fn ocvrs_mat_allocator_deallocate(allocator: *mut c_void, data: *mut u8, size: size_t) {
+	let allocator = &*(allocator as *const Box<dyn MatAllocator>);
+	let _ = panic::catch_unwind(AssertUnwindSafe(|| allocator.deallocate(data, size)));
+}
+
+#[no_mangle]
+unsafe extern "C" fn ocvrs_mat_owned_buffer_free(owner: *mut c_void) {
+	drop(Box::from_raw(owner as *mut Box<dyn Send>))
+}
@@ -2,6 +2,8 @@ use std::{
 	ffi::c_void,
 	fmt,
 	mem::ManuallyDrop,
+	ops::{Deref, DerefMut},
+	slice,
 };
 
 use num_traits::{One, Zero};
@@ -9,7 +11,6 @@ use num_traits::{One, Zero};
 use crate::{
 	core::{self, ToInputArray, ToInputOutputArray, ToOutputArray},
 	Error,
-	manual::core::sized::*,
 	Result,
 	sys::Result as SysResult,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
@@ -25,7 +26,7 @@ fn index_check(idx: (usize, usize), rows: usize, cols: usize) -> Result<()> {
 	}
 }
 
-valid_types!(ValidMatxType: f32, f64);
+valid_types!(ValidMatxType: i8, u8, i16, u16, i32, f32, f64);
 
 pub trait MatxTrait: Sized {
 	type ElemType: ValidMatxType;
@@ -87,54 +88,129 @@ pub trait MatxTrait: Sized {
 	}
 }
 
+/// Storage of the `Matx` elements, dereferences to the flat row-major slice of all elements like the `val` array of
+/// the C++ `cv::Matx`, the rows are available through the inner array
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct MatxVal<T, const R: usize, const C: usize>(pub [[T; C]; R]);
+
+impl<T, const R: usize, const C: usize> Deref for MatxVal<T, R, C> {
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		// nested arrays are laid out contiguously without padding
+		unsafe { slice::from_raw_parts(self.0.as_ptr() as *const T, R * C) }
+	}
+}
+
+impl<T, const R: usize, const C: usize> DerefMut for MatxVal<T, R, C> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe { slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut T, R * C) }
+	}
+}
+
+impl<'a, T, const R: usize, const C: usize> IntoIterator for &'a MatxVal<T, R, C> {
+	type Item = &'a T;
+	type IntoIter = slice::Iter<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, T, const R: usize, const C: usize> IntoIterator for &'a mut MatxVal<T, R, C> {
+	type Item = &'a mut T;
+	type IntoIter = slice::IterMut<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
 /// [docs.opencv.org](https://docs.opencv.org/master/de/de1/classcv_1_1Matx.html)
+///
+/// Matrix of `R` rows and `C` columns, the elements are stored in the row-major order exactly like in the C++ `cv::Matx`
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct Matx<T: ValidMatxType, A: SizedArray<T>> {
-	pub val: A::Storage,
+pub struct Matx<T: ValidMatxType, const R: usize, const C: usize> {
+	pub val: MatxVal<T, R, C>,
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> Matx<T, R, C> {
+	pub const ROWS: usize = R;
+	pub const COLS: usize = C;
+
+	/// Create a new `Matx` from the row-major slice of elements, the length of the slice must be exactly `R * C`
+	pub fn from_slice(s: &[T]) -> Result<Self> {
+		if s.len() != R * C {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Slice of {} elements doesn't match Matx of {}x{}", s.len(), R, C)));
+		}
+		let mut out = Self::zeros();
+		out.val_mut().copy_from_slice(s);
+		Ok(out)
+	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> Matx<T, A> {
-	pub fn from(s: A::Storage) -> Self {
-		Self { val: s }
+impl<T: ValidMatxType, const R: usize, const C: usize> From<[[T; C]; R]> for Matx<T, R, C> {
+	#[inline]
+	fn from(s: [[T; C]; R]) -> Self {
+		Self { val: MatxVal(s) }
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> MatxTrait for Matx<T, A> {
+/// Creates the `Matx` from the flat row-major array of elements for the shapes that have the predefined aliases
+macro_rules! matx_from_flat {
+	($rows: literal, $cols: literal, $len: literal) => {
+		impl<T: ValidMatxType> From<[T; $len]> for Matx<T, $rows, $cols> {
+			#[inline]
+			fn from(s: [T; $len]) -> Self {
+				let mut out = Self::default();
+				out.val_mut().copy_from_slice(&s);
+				out
+			}
+		}
+	};
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> MatxTrait for Matx<T, R, C> {
 	type ElemType = T;
 
 	fn rows(&self) -> usize {
-		A::ROWS
+		R
 	}
 
 	fn cols(&self) -> usize {
-		A::COLS
+		C
 	}
 
 	fn val(&self) -> &[Self::ElemType] {
-		self.val.as_ref()
+		&self.val
 	}
 
 	fn val_mut(&mut self) -> &mut [Self::ElemType] {
-		self.val.as_mut()
+		&mut self.val
 	}
 
 	fn all(alpha: Self::ElemType) -> Self where Self: Sized {
-		Self { val: A::all(alpha) }
+		Self { val: MatxVal([[alpha; C]; R]) }
 	}
 
 	fn channels(&self) -> usize {
-		A::ROWS * A::COLS
+		R * C
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> Default for Matx<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> Default for Matx<T, R, C> {
 	fn default() -> Self {
 		Self::all(T::default())
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> std::ops::Index<(usize, usize)> for Matx<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> std::ops::Index<(usize, usize)> for Matx<T, R, C> {
 	type Output = T;
 
 	fn index(&self, index: (usize, usize)) -> &Self::Output {
@@ -142,13 +218,13 @@ impl<T: ValidMatxType, A: SizedArray<T>> std::ops::Index<(usize, usize)> for Mat
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> std::ops::IndexMut<(usize, usize)> for Matx<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> std::ops::IndexMut<(usize, usize)> for Matx<T, R, C> {
 	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
 		self.get_mut(index).expect("Index out of range")
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> OpenCVType<'_> for Matx<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> OpenCVType<'_> for Matx<T, R, C> {
 	type Arg = Self;
 	type ExternReceive = Self;
 	type ExternContainer = Self;
@@ -158,7 +234,7 @@ impl<T: ValidMatxType, A: SizedArray<T>> OpenCVType<'_> for Matx<T, A> {
 	#[inline] unsafe fn opencv_from_extern(s: Self) -> Self { s }
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeArg<'_> for Matx<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> OpenCVTypeArg<'_> for Matx<T, R, C> {
 	type ExternContainer = Self;
 
 	#[inline]
@@ -167,7 +243,7 @@ impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeArg<'_> for Matx<T, A> {
 	fn opencv_into_extern_container_nofail(self) -> Self::ExternContainer { self }
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeExternContainer for Matx<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> OpenCVTypeExternContainer for Matx<T, R, C> {
 	type ExternSend = *const Self;
 	type ExternSendMut = *mut Self;
 
@@ -176,13 +252,13 @@ impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeExternContainer for Matx<T, A
 	#[inline] fn opencv_into_extern(self) -> Self::ExternSendMut { &mut *ManuallyDrop::new(self) as _ }
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> std::cmp::PartialEq for Matx<T, A> {
-	fn eq(&self, other: &Matx<T, A>) -> bool {
+impl<T: ValidMatxType, const R: usize, const C: usize> std::cmp::PartialEq for Matx<T, R, C> {
+	fn eq(&self, other: &Matx<T, R, C>) -> bool {
 		self.val() == other.val()
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> fmt::Debug for Matx<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> fmt::Debug for Matx<T, R, C> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		f.debug_struct("Matx")
 			.field("rows", &self.rows())
@@ -193,7 +269,7 @@ impl<T: ValidMatxType, A: SizedArray<T>> fmt::Debug for Matx<T, A> {
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputArray for Matx<T, A> where Self: MatxExtern<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> ToInputArray for Matx<T, R, C> where Self: MatxExtern {
 	fn input_array(&self) -> Result<core::_InputArray> {
 		unsafe { self.extern_input_array() }
 			.into_result()
@@ -201,13 +277,13 @@ impl<T: ValidMatxType, A: SizedArray<T>> ToInputArray for Matx<T, A> where Self:
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputArray for &Matx<T, A> where Matx<T, A>: MatxExtern<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> ToInputArray for &Matx<T, R, C> where Matx<T, R, C>: MatxExtern {
 	fn input_array(&self) -> Result<core::_InputArray> {
 		(*self).input_array()
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToOutputArray for Matx<T, A> where Self: MatxExtern<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> ToOutputArray for Matx<T, R, C> where Self: MatxExtern {
 	fn output_array(&mut self) -> Result<core::_OutputArray> {
 		unsafe { self.extern_output_array() }
 			.into_result()
@@ -215,13 +291,13 @@ impl<T: ValidMatxType, A: SizedArray<T>> ToOutputArray for Matx<T, A> where Self
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToOutputArray for &mut Matx<T, A> where Matx<T, A>: MatxExtern<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> ToOutputArray for &mut Matx<T, R, C> where Matx<T, R, C>: MatxExtern {
 	fn output_array(&mut self) -> Result<core::_OutputArray> {
 		(*self).output_array()
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputOutputArray for Matx<T, A> where Self: MatxExtern<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> ToInputOutputArray for Matx<T, R, C> where Self: MatxExtern {
 	fn input_output_array(&mut self) -> Result<core::_InputOutputArray> {
 		unsafe { self.extern_input_output_array() }
 			.into_result()
@@ -229,90 +305,106 @@ impl<T: ValidMatxType, A: SizedArray<T>> ToInputOutputArray for Matx<T, A> where
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputOutputArray for &mut Matx<T, A> where Matx<T, A>: MatxExtern<T, A> {
+impl<T: ValidMatxType, const R: usize, const C: usize> ToInputOutputArray for &mut Matx<T, R, C> where Matx<T, R, C>: MatxExtern {
 	fn input_output_array(&mut self) -> Result<core::_InputOutputArray> {
 		(*self).input_output_array()
 	}
 }
 
 #[doc(hidden)]
-pub trait MatxExtern<T: ValidMatxType, A: SizedArray<T>> {
+pub trait MatxExtern {
 	#[doc(hidden)] unsafe fn extern_input_array(&self) -> SysResult<*mut c_void>;
 	#[doc(hidden)] unsafe fn extern_output_array(&mut self) -> SysResult<*mut c_void>;
 	#[doc(hidden)] unsafe fn extern_input_output_array(&mut self) -> SysResult<*mut c_void>;
 }
 
 macro_rules! matx_extern {
-	($type: ty, $array: ty, $extern_input_array: ident, $extern_ouput_array: ident, $extern_input_array_output: ident) => {
-		impl $crate::manual::core::MatxExtern<$type, $array> for $crate::manual::core::Matx<$type, $array> {
+	($type: ty, $rows: literal, $cols: literal, $extern_input_array: ident, $extern_ouput_array: ident, $extern_input_array_output: ident) => {
+		impl $crate::manual::core::MatxExtern for $crate::manual::core::Matx<$type, $rows, $cols> {
 			unsafe fn extern_input_array(&self) -> $crate::sys::Result<*mut c_void> {
-				extern "C" { fn $extern_input_array(instance: *const $crate::manual::core::Matx<$type, $array>) -> $crate::sys::Result<*mut c_void>; }
+				extern "C" { fn $extern_input_array(instance: *const $crate::manual::core::Matx<$type, $rows, $cols>) -> $crate::sys::Result<*mut c_void>; }
 				$extern_input_array(self)
 			}
 
 			unsafe fn extern_output_array(&mut self) -> $crate::sys::Result<*mut c_void> {
-				extern "C" { fn $extern_ouput_array(instance: *mut $crate::manual::core::Matx<$type, $array>) -> $crate::sys::Result<*mut c_void>; }
+				extern "C" { fn $extern_ouput_array(instance: *mut $crate::manual::core::Matx<$type, $rows, $cols>) -> $crate::sys::Result<*mut c_void>; }
 				$extern_ouput_array(self)
 			}
 
 			unsafe fn extern_input_output_array(&mut self) -> $crate::sys::Result<*mut c_void> {
-				extern "C" { fn $extern_input_array_output(instance: *mut $crate::manual::core::Matx<$type, $array>) -> $crate::sys::Result<*mut c_void>; }
+				extern "C" { fn $extern_input_array_output(instance: *mut $crate::manual::core::Matx<$type, $rows, $cols>) -> $crate::sys::Result<*mut c_void>; }
 				$extern_input_array_output(self)
 			}
 		}
 	}
 }
 
-pub type Matx12<T> = Matx<T, SizedArray12>;
-matx_extern!(f32, SizedArray12, cv_Matx12f_input_array, cv_Matx12f_output_array, cv_Matx12f_input_output_array);
-matx_extern!(f64, SizedArray12, cv_Matx12d_input_array, cv_Matx12d_output_array, cv_Matx12d_input_output_array);
-pub type Matx13<T> = Matx<T, SizedArray13>;
-matx_extern!(f32, SizedArray13, cv_Matx13f_input_array, cv_Matx13f_output_array, cv_Matx13f_input_output_array);
-matx_extern!(f64, SizedArray13, cv_Matx13d_input_array, cv_Matx13d_output_array, cv_Matx13d_input_output_array);
-pub type Matx14<T> = Matx<T, SizedArray14>;
-matx_extern!(f32, SizedArray14, cv_Matx14f_input_array, cv_Matx14f_output_array, cv_Matx14f_input_output_array);
-matx_extern!(f64, SizedArray14, cv_Matx14d_input_array, cv_Matx14d_output_array, cv_Matx14d_input_output_array);
-pub type Matx16<T> = Matx<T, SizedArray16>;
-matx_extern!(f32, SizedArray16, cv_Matx16f_input_array, cv_Matx16f_output_array, cv_Matx16f_input_output_array);
-matx_extern!(f64, SizedArray16, cv_Matx16d_input_array, cv_Matx16d_output_array, cv_Matx16d_input_output_array);
-
-pub type Matx21<T> = Matx<T, SizedArray21>;
-matx_extern!(f32, SizedArray21, cv_Matx21f_input_array, cv_Matx21f_output_array, cv_Matx21f_input_output_array);
-matx_extern!(f64, SizedArray21, cv_Matx21d_input_array, cv_Matx21d_output_array, cv_Matx21d_input_output_array);
-pub type Matx31<T> = Matx<T, SizedArray31>;
-matx_extern!(f32, SizedArray31, cv_Matx31f_input_array, cv_Matx31f_output_array, cv_Matx31f_input_output_array);
-matx_extern!(f64, SizedArray31, cv_Matx31d_input_array, cv_Matx31d_output_array, cv_Matx31d_input_output_array);
-pub type Matx41<T> = Matx<T, SizedArray41>;
-matx_extern!(f32, SizedArray41, cv_Matx41f_input_array, cv_Matx41f_output_array, cv_Matx41f_input_output_array);
-matx_extern!(f64, SizedArray41, cv_Matx41d_input_array, cv_Matx41d_output_array, cv_Matx41d_input_output_array);
-pub type Matx61<T> = Matx<T, SizedArray61>;
-matx_extern!(f32, SizedArray61, cv_Matx61f_input_array, cv_Matx61f_output_array, cv_Matx61f_input_output_array);
-matx_extern!(f64, SizedArray61, cv_Matx61d_input_array, cv_Matx61d_output_array, cv_Matx61d_input_output_array);
-
-pub type Matx22<T> = Matx<T, SizedArray22>;
-matx_extern!(f32, SizedArray22, cv_Matx22f_input_array, cv_Matx22f_output_array, cv_Matx22f_input_output_array);
-matx_extern!(f64, SizedArray22, cv_Matx22d_input_array, cv_Matx22d_output_array, cv_Matx22d_input_output_array);
-pub type Matx23<T> = Matx<T, SizedArray23>;
-matx_extern!(f32, SizedArray23, cv_Matx23f_input_array, cv_Matx23f_output_array, cv_Matx23f_input_output_array);
-matx_extern!(f64, SizedArray23, cv_Matx23d_input_array, cv_Matx23d_output_array, cv_Matx23d_input_output_array);
-pub type Matx32<T> = Matx<T, SizedArray32>;
-matx_extern!(f32, SizedArray32, cv_Matx32f_input_array, cv_Matx32f_output_array, cv_Matx32f_input_output_array);
-matx_extern!(f64, SizedArray32, cv_Matx32d_input_array, cv_Matx32d_output_array, cv_Matx32d_input_output_array);
-
-pub type Matx33<T> = Matx<T, SizedArray33>;
-matx_extern!(f32, SizedArray33, cv_Matx33f_input_array, cv_Matx33f_output_array, cv_Matx33f_input_output_array);
-matx_extern!(f64, SizedArray33, cv_Matx33d_input_array, cv_Matx33d_output_array, cv_Matx33d_input_output_array);
-
-pub type Matx34<T> = Matx<T, SizedArray34>;
-matx_extern!(f32, SizedArray34, cv_Matx34f_input_array, cv_Matx34f_output_array, cv_Matx34f_input_output_array);
-matx_extern!(f64, SizedArray34, cv_Matx34d_input_array, cv_Matx34d_output_array, cv_Matx34d_input_output_array);
-pub type Matx43<T> = Matx<T, SizedArray43>;
-matx_extern!(f32, SizedArray43, cv_Matx43f_input_array, cv_Matx43f_output_array, cv_Matx43f_input_output_array);
-matx_extern!(f64, SizedArray43, cv_Matx43d_input_array, cv_Matx43d_output_array, cv_Matx43d_input_output_array);
-
-pub type Matx44<T> = Matx<T, SizedArray44>;
-matx_extern!(f32, SizedArray44, cv_Matx44f_input_array, cv_Matx44f_output_array, cv_Matx44f_input_output_array);
-matx_extern!(f64, SizedArray44, cv_Matx44d_input_array, cv_Matx44d_output_array, cv_Matx44d_input_output_array);
-pub type Matx66<T> = Matx<T, SizedArray66>;
-matx_extern!(f32, SizedArray66, cv_Matx66f_input_array, cv_Matx66f_output_array, cv_Matx66f_input_output_array);
-matx_extern!(f64, SizedArray66, cv_Matx66d_input_array, cv_Matx66d_output_array, cv_Matx66d_input_output_array);
+pub type Matx12<T> = Matx<T, 1, 2>;
+matx_from_flat!(1, 2, 2);
+matx_extern!(f32, 1, 2, cv_Matx12f_input_array, cv_Matx12f_output_array, cv_Matx12f_input_output_array);
+matx_extern!(f64, 1, 2, cv_Matx12d_input_array, cv_Matx12d_output_array, cv_Matx12d_input_output_array);
+pub type Matx13<T> = Matx<T, 1, 3>;
+matx_from_flat!(1, 3, 3);
+matx_extern!(f32, 1, 3, cv_Matx13f_input_array, cv_Matx13f_output_array, cv_Matx13f_input_output_array);
+matx_extern!(f64, 1, 3, cv_Matx13d_input_array, cv_Matx13d_output_array, cv_Matx13d_input_output_array);
+pub type Matx14<T> = Matx<T, 1, 4>;
+matx_from_flat!(1, 4, 4);
+matx_extern!(f32, 1, 4, cv_Matx14f_input_array, cv_Matx14f_output_array, cv_Matx14f_input_output_array);
+matx_extern!(f64, 1, 4, cv_Matx14d_input_array, cv_Matx14d_output_array, cv_Matx14d_input_output_array);
+pub type Matx16<T> = Matx<T, 1, 6>;
+matx_from_flat!(1, 6, 6);
+matx_extern!(f32, 1, 6, cv_Matx16f_input_array, cv_Matx16f_output_array, cv_Matx16f_input_output_array);
+matx_extern!(f64, 1, 6, cv_Matx16d_input_array, cv_Matx16d_output_array, cv_Matx16d_input_output_array);
+
+pub type Matx21<T> = Matx<T, 2, 1>;
+matx_from_flat!(2, 1, 2);
+matx_extern!(f32, 2, 1, cv_Matx21f_input_array, cv_Matx21f_output_array, cv_Matx21f_input_output_array);
+matx_extern!(f64, 2, 1, cv_Matx21d_input_array, cv_Matx21d_output_array, cv_Matx21d_input_output_array);
+pub type Matx31<T> = Matx<T, 3, 1>;
+matx_from_flat!(3, 1, 3);
+matx_extern!(f32, 3, 1, cv_Matx31f_input_array, cv_Matx31f_output_array, cv_Matx31f_input_output_array);
+matx_extern!(f64, 3, 1, cv_Matx31d_input_array, cv_Matx31d_output_array, cv_Matx31d_input_output_array);
+pub type Matx41<T> = Matx<T, 4, 1>;
+matx_from_flat!(4, 1, 4);
+matx_extern!(f32, 4, 1, cv_Matx41f_input_array, cv_Matx41f_output_array, cv_Matx41f_input_output_array);
+matx_extern!(f64, 4, 1, cv_Matx41d_input_array, cv_Matx41d_output_array, cv_Matx41d_input_output_array);
+pub type Matx61<T> = Matx<T, 6, 1>;
+matx_from_flat!(6, 1, 6);
+matx_extern!(f32, 6, 1, cv_Matx61f_input_array, cv_Matx61f_output_array, cv_Matx61f_input_output_array);
+matx_extern!(f64, 6, 1, cv_Matx61d_input_array, cv_Matx61d_output_array, cv_Matx61d_input_output_array);
+
+pub type Matx22<T> = Matx<T, 2, 2>;
+matx_from_flat!(2, 2, 4);
+matx_extern!(f32, 2, 2, cv_Matx22f_input_array, cv_Matx22f_output_array, cv_Matx22f_input_output_array);
+matx_extern!(f64, 2, 2, cv_Matx22d_input_array, cv_Matx22d_output_array, cv_Matx22d_input_output_array);
+pub type Matx23<T> = Matx<T, 2, 3>;
+matx_from_flat!(2, 3, 6);
+matx_extern!(f32, 2, 3, cv_Matx23f_input_array, cv_Matx23f_output_array, cv_Matx23f_input_output_array);
+matx_extern!(f64, 2, 3, cv_Matx23d_input_array, cv_Matx23d_output_array, cv_Matx23d_input_output_array);
+pub type Matx32<T> = Matx<T, 3, 2>;
+matx_from_flat!(3, 2, 6);
+matx_extern!(f32, 3, 2, cv_Matx32f_input_array, cv_Matx32f_output_array, cv_Matx32f_input_output_array);
+matx_extern!(f64, 3, 2, cv_Matx32d_input_array, cv_Matx32d_output_array, cv_Matx32d_input_output_array);
+
+pub type Matx33<T> = Matx<T, 3, 3>;
+matx_from_flat!(3, 3, 9);
+matx_extern!(f32, 3, 3, cv_Matx33f_input_array, cv_Matx33f_output_array, cv_Matx33f_input_output_array);
+matx_extern!(f64, 3, 3, cv_Matx33d_input_array, cv_Matx33d_output_array, cv_Matx33d_input_output_array);
+
+pub type Matx34<T> = Matx<T, 3, 4>;
+matx_from_flat!(3, 4, 12);
+matx_extern!(f32, 3, 4, cv_Matx34f_input_array, cv_Matx34f_output_array, cv_Matx34f_input_output_array);
+matx_extern!(f64, 3, 4, cv_Matx34d_input_array, cv_Matx34d_output_array, cv_Matx34d_input_output_array);
+pub type Matx43<T> = Matx<T, 4, 3>;
+matx_from_flat!(4, 3, 12);
+matx_extern!(f32, 4, 3, cv_Matx43f_input_array, cv_Matx43f_output_array, cv_Matx43f_input_output_array);
+matx_extern!(f64, 4, 3, cv_Matx43d_input_array, cv_Matx43d_output_array, cv_Matx43d_input_output_array);
+
+pub type Matx44<T> = Matx<T, 4, 4>;
+matx_from_flat!(4, 4, 16);
+matx_extern!(f32, 4, 4, cv_Matx44f_input_array, cv_Matx44f_output_array, cv_Matx44f_input_output_array);
+matx_extern!(f64, 4, 4, cv_Matx44d_input_array, cv_Matx44d_output_array, cv_Matx44d_input_output_array);
+pub type Matx66<T> = Matx<T, 6, 6>;
+matx_from_flat!(6, 6, 36);
+matx_extern!(f32, 6, 6, cv_Matx66f_input_array, cv_Matx66f_output_array, cv_Matx66f_input_output_array);
+matx_extern!(f64, 6, 6, cv_Matx66d_input_array, cv_Matx66d_output_array, cv_Matx66d_input_output_array);
@@ -0,0 +1,303 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{Float, NumCast};
+
+use crate::{
+	core::{Matx, MatxTrait, ValidMatxType, ValidVecType, VecN},
+};
+
+/// Multiply row-major `a` (`rows`x`inner`) by row-major `b` (`inner`x`cols`) storing the result in `out`
+#[inline]
+fn mat_mul<T: ValidMatxType>(a: &[T], b: &[T], out: &mut [T], rows: usize, inner: usize, cols: usize) {
+	for row in 0..rows {
+		for col in 0..cols {
+			let mut sum = T::zero();
+			for i in 0..inner {
+				sum += a[row * inner + i] * b[i * cols + col];
+			}
+			out[row * cols + col] = sum;
+		}
+	}
+}
+
+/// Threshold below which the pivot is considered zero and the matrix singular, similar to the one used in `cv::LU`
+#[inline]
+fn singular_eps<T: Float>() -> T {
+	T::epsilon() * <T as NumCast>::from(100).expect("Can't convert constant")
+}
+
+/// Row in `col..n` with the largest absolute value in the column `col` of the row-major `n`x`n` matrix `a`
+///
+/// Returns `None` if the pivot is below `singular_eps()` or if the column contains NaN.
+fn find_pivot<T: Float>(a: &[T], n: usize, col: usize) -> Option<usize> {
+	let mut pivot_row = col;
+	for row in col..n {
+		let val = a[row * n + col].abs();
+		if val.is_nan() {
+			return None;
+		}
+		if val > a[pivot_row * n + col].abs() {
+			pivot_row = row;
+		}
+	}
+	if a[pivot_row * n + col].abs() < singular_eps() {
+		None
+	} else {
+		Some(pivot_row)
+	}
+}
+
+/// Solve `a * x = b` with Gaussian elimination with partial pivoting
+///
+/// `a` is row-major `n`x`n` matrix, it's destroyed in the process. `b` is row-major `n`x`m` matrix, it's replaced by
+/// the solution. Returns `false` if `a` is singular or contains NaN.
+fn gauss_solve<T: ValidMatxType + Float>(a: &mut [T], b: &mut [T], n: usize, m: usize) -> bool {
+	for col in 0..n {
+		let pivot_row = match find_pivot(a, n, col) {
+			Some(pivot_row) => pivot_row,
+			None => return false,
+		};
+		if pivot_row != col {
+			(0..n).for_each(|i| a.swap(pivot_row * n + i, col * n + i));
+			(0..m).for_each(|i| b.swap(pivot_row * m + i, col * m + i));
+		}
+		let pivot = a[col * n + col];
+		for row in 0..n {
+			if row != col {
+				let factor = a[row * n + col] / pivot;
+				if !factor.is_zero() {
+					(col..n).for_each(|i| a[row * n + i] -= factor * a[col * n + i]);
+					(0..m).for_each(|i| b[row * m + i] -= factor * b[col * m + i]);
+				}
+			}
+		}
+	}
+	for row in 0..n {
+		let pivot = a[row * n + row];
+		(0..m).for_each(|i| b[row * m + i] /= pivot);
+	}
+	true
+}
+
+/// Determinant of the row-major `n`x`n` matrix `a` using LU decomposition with partial pivoting, `a` is destroyed
+///
+/// Returns zero if the matrix is singular and NaN if it contains NaN.
+fn lu_det<T: ValidMatxType + Float>(a: &mut [T], n: usize) -> T {
+	if a.iter().any(|x| x.is_nan()) {
+		return T::nan();
+	}
+	let mut det = T::one();
+	for col in 0..n {
+		let pivot_row = match find_pivot(a, n, col) {
+			Some(pivot_row) => pivot_row,
+			None => return T::zero(),
+		};
+		let pivot = a[pivot_row * n + col];
+		if pivot_row != col {
+			(0..n).for_each(|i| a.swap(pivot_row * n + i, col * n + i));
+			det = -det;
+		}
+		det *= pivot;
+		for row in col + 1..n {
+			let factor = a[row * n + col] / pivot;
+			(col..n).for_each(|i| a[row * n + i] -= factor * a[col * n + i]);
+		}
+	}
+	det
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> AddAssign for Matx<T, R, C> {
+	#[inline]
+	fn add_assign(&mut self, rhs: Self) {
+		self.val_mut().iter_mut().zip(rhs.val()).for_each(|(x, &y)| *x += y);
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> SubAssign for Matx<T, R, C> {
+	#[inline]
+	fn sub_assign(&mut self, rhs: Self) {
+		self.val_mut().iter_mut().zip(rhs.val()).for_each(|(x, &y)| *x -= y);
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> MulAssign<T> for Matx<T, R, C> {
+	#[inline]
+	fn mul_assign(&mut self, rhs: T) {
+		self.val_mut().iter_mut().for_each(|x| *x *= rhs);
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> DivAssign<T> for Matx<T, R, C> {
+	#[inline]
+	fn div_assign(&mut self, rhs: T) {
+		self.val_mut().iter_mut().for_each(|x| *x /= rhs);
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> Add for Matx<T, R, C> {
+	type Output = Self;
+
+	#[inline]
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> Sub for Matx<T, R, C> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= rhs;
+		self
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> Mul<T> for Matx<T, R, C> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(mut self, rhs: T) -> Self::Output {
+		self *= rhs;
+		self
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> Div<T> for Matx<T, R, C> {
+	type Output = Self;
+
+	#[inline]
+	fn div(mut self, rhs: T) -> Self::Output {
+		self /= rhs;
+		self
+	}
+}
+
+impl<T: ValidMatxType + Neg<Output=T>, const R: usize, const C: usize> Neg for Matx<T, R, C> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(mut self) -> Self::Output {
+		self.val_mut().iter_mut().for_each(|x| *x = -*x);
+		self
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> Matx<T, R, C> {
+	/// Per-element multiplication
+	#[inline]
+	pub fn mul_elem(mut self, rhs: Self) -> Self {
+		self.val_mut().iter_mut().zip(rhs.val()).for_each(|(x, &y)| *x *= y);
+		self
+	}
+
+	/// Per-element division
+	#[inline]
+	pub fn div_elem(mut self, rhs: Self) -> Self {
+		self.val_mut().iter_mut().zip(rhs.val()).for_each(|(x, &y)| *x /= y);
+		self
+	}
+
+	/// Dot product computed as if the matrices were 1D vectors
+	#[inline]
+	pub fn dot(&self, rhs: &Self) -> T {
+		self.val().iter().zip(rhs.val()).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const C: usize> Matx<T, R, C> {
+	/// Transposed matrix
+	#[inline]
+	pub fn t(&self) -> Matx<T, C, R> {
+		let mut out = Matx::<T, C, R>::zeros();
+		for row in 0..R {
+			for col in 0..C {
+				out.val.0[col][row] = self.val.0[row][col];
+			}
+		}
+		out
+	}
+}
+
+impl<T: ValidMatxType + ValidVecType + Float, const N: usize> Matx<T, N, N> {
+	/// Matrix determinant, zero if the matrix is singular (within the same tolerance as `inv()` and `solve()`) and NaN
+	/// if it contains NaN
+	#[inline]
+	pub fn det(&self) -> T {
+		let mut a = *self;
+		lu_det(a.val_mut(), N)
+	}
+
+	/// Inverse matrix, `None` if the matrix is singular or contains NaN
+	#[inline]
+	pub fn inv(&self) -> Option<Self> {
+		let mut a = *self;
+		let mut out = Self::eye();
+		if gauss_solve(a.val_mut(), out.val_mut(), N, N) {
+			Some(out)
+		} else {
+			None
+		}
+	}
+
+	/// Solve linear system `self * x = rhs`, `None` if the matrix is singular or contains NaN
+	#[inline]
+	pub fn solve<const M: usize>(&self, mut rhs: Matx<T, N, M>) -> Option<Matx<T, N, M>> {
+		let mut a = *self;
+		if gauss_solve(a.val_mut(), rhs.val_mut(), N, M) {
+			Some(rhs)
+		} else {
+			None
+		}
+	}
+
+	/// Solve linear system `self * x = rhs`, `None` if the matrix is singular or contains NaN
+	#[inline]
+	pub fn solve_vec(&self, mut rhs: VecN<T, N>) -> Option<VecN<T, N>> {
+		let mut a = *self;
+		if gauss_solve(a.val_mut(), &mut rhs[..], N, 1) {
+			Some(rhs)
+		} else {
+			None
+		}
+	}
+
+	/// Diagonal of the matrix
+	#[inline]
+	pub fn diag(&self) -> VecN<T, N> {
+		let mut out = VecN::all(T::zero());
+		(0..N).for_each(|i| out[i] = self.val.0[i][i]);
+		out
+	}
+
+	/// Diagonal matrix with the elements of `d` on the diagonal
+	#[inline]
+	pub fn from_diag(d: VecN<T, N>) -> Self {
+		let mut out = Self::zeros();
+		(0..N).for_each(|i| out.val.0[i][i] = d[i]);
+		out
+	}
+}
+
+impl<T: ValidMatxType, const R: usize, const K: usize, const C: usize> Mul<Matx<T, K, C>> for Matx<T, R, K> {
+	type Output = Matx<T, R, C>;
+
+	#[inline]
+	fn mul(self, rhs: Matx<T, K, C>) -> Self::Output {
+		let mut out = Self::Output::zeros();
+		mat_mul(self.val(), rhs.val(), out.val_mut(), R, K, C);
+		out
+	}
+}
+
+impl<T: ValidMatxType + ValidVecType, const R: usize, const C: usize> Mul<VecN<T, C>> for Matx<T, R, C> {
+	type Output = VecN<T, R>;
+
+	#[inline]
+	fn mul(self, rhs: VecN<T, C>) -> Self::Output {
+		let mut out = VecN::all(T::zero());
+		mat_mul(self.val(), &rhs[..], &mut out[..], R, C, 1);
+		out
+	}
+}
@@ -0,0 +1,116 @@
+//! Conversions between the small fixed-size OpenCV types and `nalgebra` types, enabled by the `nalgebra` cargo feature
+
+use std::convert::TryFrom;
+
+use nalgebra as na;
+
+use crate::{
+	core::{
+		self,
+		Affine3,
+		Matx,
+		MatxTrait,
+		Point3_,
+		Point_,
+		ValidMatxType,
+		ValidPoint3Type,
+		ValidPointType,
+		ValidVecType,
+		VecN,
+	},
+	Error,
+	Result,
+};
+
+impl<T: ValidMatxType + na::Scalar, const R: usize, const C: usize> From<Matx<T, R, C>> for na::SMatrix<T, R, C> {
+	#[inline]
+	fn from(s: Matx<T, R, C>) -> Self {
+		// Matx is row-major
+		Self::from_row_slice(s.val())
+	}
+}
+
+impl<T: ValidMatxType + na::Scalar, const R: usize, const C: usize> From<na::SMatrix<T, R, C>> for Matx<T, R, C> {
+	#[inline]
+	fn from(s: na::SMatrix<T, R, C>) -> Self {
+		// nalgebra matrix is column-major, so the data of the transposed one is in the row-major order
+		let mut out = Self::zeros();
+		out.val_mut().copy_from_slice(s.transpose().as_slice());
+		out
+	}
+}
+
+impl<T: ValidVecType + na::Scalar, const N: usize> From<VecN<T, N>> for na::SVector<T, N> {
+	#[inline]
+	fn from(s: VecN<T, N>) -> Self {
+		Self::from(s.0)
+	}
+}
+
+impl<T: ValidVecType + na::Scalar, const N: usize> From<na::SVector<T, N>> for VecN<T, N> {
+	#[inline]
+	fn from(s: na::SVector<T, N>) -> Self {
+		Self(s.into())
+	}
+}
+
+impl<T: ValidPointType + na::Scalar> From<Point_<T>> for na::Point2<T> {
+	#[inline]
+	fn from(s: Point_<T>) -> Self {
+		Self::new(s.x, s.y)
+	}
+}
+
+impl<T: ValidPointType + na::Scalar> From<na::Point2<T>> for Point_<T> {
+	#[inline]
+	fn from(s: na::Point2<T>) -> Self {
+		Self::new(s.x, s.y)
+	}
+}
+
+impl<T: ValidPoint3Type + na::Scalar> From<Point3_<T>> for na::Point3<T> {
+	#[inline]
+	fn from(s: Point3_<T>) -> Self {
+		Self::new(s.x, s.y, s.z)
+	}
+}
+
+impl<T: ValidPoint3Type + na::Scalar> From<na::Point3<T>> for Point3_<T> {
+	#[inline]
+	fn from(s: na::Point3<T>) -> Self {
+		Self::new(s.x, s.y, s.z)
+	}
+}
+
+impl<T: ValidMatxType + na::RealField> From<Affine3<T>> for na::Affine3<T> {
+	#[inline]
+	fn from(s: Affine3<T>) -> Self {
+		// cv::Affine3 always keeps the last row of the matrix as [0, 0, 0, 1]
+		Self::from_matrix_unchecked(s.matrix.into())
+	}
+}
+
+impl<T: ValidMatxType + na::RealField> From<na::Affine3<T>> for Affine3<T> {
+	#[inline]
+	fn from(s: na::Affine3<T>) -> Self {
+		Self { matrix: s.to_homogeneous().into() }
+	}
+}
+
+impl<T: ValidMatxType + na::RealField> From<na::Isometry3<T>> for Affine3<T> {
+	#[inline]
+	fn from(s: na::Isometry3<T>) -> Self {
+		Self { matrix: s.to_homogeneous().into() }
+	}
+}
+
+/// Fails if the linear part of the `Affine3` is not a rotation (e.g. it contains scaling or shearing)
+impl<T: ValidMatxType + na::RealField> TryFrom<Affine3<T>> for na::Isometry3<T> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(s: Affine3<T>) -> Result<Self> {
+		na::try_convert(na::Matrix4::from(s.matrix))
+			.ok_or_else(|| Error::new(core::StsBadArg, "Affine3 is not an isometry, it contains scaling or shearing".to_string()))
+	}
+}
@@ -8,6 +8,7 @@ valid_types!(ValidPoint3Type: i32, f32, f64);
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [docs.opencv.org](https://docs.opencv.org/master/df/d6c/classcv_1_1Point3__.html)
 pub struct Point3_<T: ValidPoint3Type> {
 	pub x: T,
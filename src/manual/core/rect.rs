@@ -22,6 +22,7 @@ fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [docs.opencv.org](https://docs.opencv.org/master/d2/d44/classcv_1_1Rect__.html)
 pub struct Rect_<T: ValidRectType> {
 	pub x: T,
@@ -0,0 +1,208 @@
+//! `Serialize` and `Deserialize` implementations for the core types that can't derive them, enabled by the `serde`
+//! cargo feature
+
+use std::{
+	fmt,
+	marker::PhantomData,
+};
+
+use serde::{
+	de::{self, SeqAccess, Visitor},
+	Deserialize,
+	Deserializer,
+	ser::SerializeTuple,
+	Serialize,
+	Serializer,
+};
+
+use crate::{
+	core::{
+		DMatch,
+		KeyPoint,
+		Matx,
+		Point2f,
+		RotatedRect,
+		RotatedRectTrait,
+		Size2f,
+		TermCriteria,
+		ValidMatxType,
+		ValidVecType,
+		VecN,
+		Vector,
+		VectorElement,
+		VectorExtern,
+	},
+};
+
+/// Serializes the slice as a fixed size tuple, the length is known from the type so it's not stored in the compact
+/// formats
+struct Tuple<'a, T>(&'a [T]);
+
+impl<T: Serialize> Serialize for Tuple<'_, T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut out = serializer.serialize_tuple(self.0.len())?;
+		for x in self.0 {
+			out.serialize_element(x)?;
+		}
+		out.end()
+	}
+}
+
+struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de> + Default + Copy, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+	type Value = [T; N];
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "an array of {} elements", N)
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut out = [T::default(); N];
+		for (i, x) in out.iter_mut().enumerate() {
+			*x = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+		}
+		Ok(out)
+	}
+}
+
+fn deserialize_array<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+	where
+		D: Deserializer<'de>,
+		T: Deserialize<'de> + Default + Copy,
+{
+	deserializer.deserialize_tuple(N, ArrayVisitor::<T, N>(PhantomData))
+}
+
+/// Single `Matx` row used during deserialization
+#[derive(Copy, Clone)]
+struct Row<T, const C: usize>([T; C]);
+
+impl<T: Default + Copy, const C: usize> Default for Row<T, C> {
+	fn default() -> Self {
+		Self([T::default(); C])
+	}
+}
+
+impl<'de, T: Deserialize<'de> + Default + Copy, const C: usize> Deserialize<'de> for Row<T, C> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_array(deserializer).map(Self)
+	}
+}
+
+/// Serialized as a tuple of `N` elements
+impl<T: ValidVecType + Serialize, const N: usize> Serialize for VecN<T, N> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		Tuple(&self.0).serialize(serializer)
+	}
+}
+
+impl<'de, T: ValidVecType + Deserialize<'de>, const N: usize> Deserialize<'de> for VecN<T, N> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_array(deserializer).map(Self)
+	}
+}
+
+/// Serialized as a tuple of `R` rows, each row is a tuple of `C` elements
+impl<T: ValidMatxType + Serialize, const R: usize, const C: usize> Serialize for Matx<T, R, C> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut out = serializer.serialize_tuple(R)?;
+		for row in &self.val.0 {
+			out.serialize_element(&Tuple(row))?;
+		}
+		out.end()
+	}
+}
+
+impl<'de, T: ValidMatxType + Deserialize<'de>, const R: usize, const C: usize> Deserialize<'de> for Matx<T, R, C> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let rows: [Row<T, C>; R] = deserialize_array(deserializer)?;
+		let mut out = Self::default();
+		out.val.0.iter_mut().zip(rows.iter()).for_each(|(dst, src)| *dst = src.0);
+		Ok(out)
+	}
+}
+
+/// Serialized as a sequence of elements
+impl<T: VectorElement + Serialize> Serialize for Vector<T> where Self: VectorExtern<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self)
+	}
+}
+
+struct VectorVisitor<T>(PhantomData<T>);
+
+impl<'de, T: VectorElement + Deserialize<'de>> Visitor<'de> for VectorVisitor<T> where Vector<T>: VectorExtern<T> {
+	type Value = Vector<T>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a sequence")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		// elements are deserialized as owned values, `Arg` of some types (e.g. `&str` for `String`) can only be
+		// borrowed from the input which is not possible for escaped strings or non-borrowing deserializers
+		let mut out = Vector::new();
+		while let Some(x) = seq.next_element::<T>()? {
+			out.push_owned(x);
+		}
+		Ok(out)
+	}
+}
+
+impl<'de, T: VectorElement + Deserialize<'de>> Deserialize<'de> for Vector<T> where Self: VectorExtern<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_seq(VectorVisitor(PhantomData))
+	}
+}
+
+// generated structs can't derive the traits directly, so the field layout is mirrored for them
+
+macro_rules! serde_remote {
+	($type: ident, $name: literal, $def: ident { $($field: ident: $field_type: ty),+ $(,)? }) => {
+		#[derive(Serialize, Deserialize)]
+		#[serde(remote = $name, rename = $name)]
+		struct $def {
+			$($field: $field_type),+
+		}
+
+		impl Serialize for $type {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				$def::serialize(self, serializer)
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $type {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				$def::deserialize(deserializer)
+			}
+		}
+	};
+}
+
+serde_remote!(KeyPoint, "KeyPoint", KeyPointDef { pt: Point2f, size: f32, angle: f32, response: f32, octave: i32, class_id: i32 });
+serde_remote!(DMatch, "DMatch", DMatchDef { query_idx: i32, train_idx: i32, img_idx: i32, distance: f32 });
+serde_remote!(TermCriteria, "TermCriteria", TermCriteriaDef { typ: i32, max_count: i32, epsilon: f64 });
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "RotatedRect")]
+struct RotatedRectData {
+	center: Point2f,
+	size: Size2f,
+	angle: f32,
+}
+
+impl Serialize for RotatedRect {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		RotatedRectData { center: self.center(), size: self.size(), angle: self.angle() }
+			.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for RotatedRect {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let data = RotatedRectData::deserialize(deserializer)?;
+		RotatedRect::new(data.center, data.size, data.angle)
+			.map_err(de::Error::custom)
+	}
+}
@@ -1,4 +1,7 @@
-use std::ffi::c_void;
+use std::{
+	ffi::c_void,
+	ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
 use crate::{
 	core::{_InputArray, ToInputArray},
@@ -12,32 +15,148 @@ pub use self::{scalar_inner::ValidScalarType, vec_inner::ValidVecType};
 // additional modules needed because valid_types! introduces module named "private"
 mod vec_inner {
 	valid_types!(ValidVecType: i8, u8, i16, u16, i32, f32, f64);
+
+	#[cfg(all(feature = "half", feature = "opencv-4"))]
+	impl ValidVecType for half::f16 {}
+	#[cfg(all(feature = "half", feature = "opencv-4"))]
+	impl private::Sealed for half::f16 {}
 }
 
 mod scalar_inner {
 	valid_types!(ValidScalarType: i32, f64);
 }
 
-macro_rules! vec_impl {
-	($type: ident, $count: expr, $type_trait: ident) => {
-		/// [docs.opencv.org](https://docs.opencv.org/master/d6/dcf/classcv_1_1Vec.html)
-		#[repr(C)]
-		#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
-		pub struct $type<T: $type_trait>(pub [T; $count]);
+/// [docs.opencv.org](https://docs.opencv.org/master/d6/dcf/classcv_1_1Vec.html)
+///
+/// Vector of `N` elements, has the same layout as the C++ `cv::Vec`
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct VecN<T: ValidVecType, const N: usize>(pub [T; N]);
+
+macro_rules! vec_alias {
+	($alias: ident, $count: literal) => {
+		pub type $alias<T> = VecN<T, $count>;
+	};
+}
+
+vec_alias!(Vec2, 2);
+vec_alias!(Vec3, 3);
+vec_alias!(Vec4, 4);
+vec_alias!(Vec6, 6);
+vec_alias!(Vec8, 8);
+vec_alias!(Vec18, 18);
 
-		impl<T: $type_trait> $type<T> {
+/// [docs.opencv.org](https://docs.opencv.org/master/d1/da0/classcv_1_1Scalar__.html)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scalar_<T: ValidScalarType>(pub [T; 4]);
+
+macro_rules! vec_impl {
+	($type: ty, $count: expr, [$($generics: tt)*]) => {
+		impl<$($generics)*> $type {
 			pub fn all(v0: T) -> Self {
 				Self::from([v0; $count])
 			}
+
+			/// Dot product
+			#[inline]
+			pub fn dot(&self, rhs: &Self) -> T {
+				self.0.iter().zip(rhs.0.iter()).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+			}
+		}
+
+		impl<$($generics)*> AddAssign for $type {
+			#[inline]
+			fn add_assign(&mut self, rhs: Self) {
+				self.0.iter_mut().zip(rhs.0.iter()).for_each(|(x, &y)| *x += y);
+			}
+		}
+
+		impl<$($generics)*> SubAssign for $type {
+			#[inline]
+			fn sub_assign(&mut self, rhs: Self) {
+				self.0.iter_mut().zip(rhs.0.iter()).for_each(|(x, &y)| *x -= y);
+			}
+		}
+
+		impl<$($generics)*> MulAssign<T> for $type {
+			#[inline]
+			fn mul_assign(&mut self, rhs: T) {
+				self.0.iter_mut().for_each(|x| *x *= rhs);
+			}
+		}
+
+		impl<$($generics)*> DivAssign<T> for $type {
+			#[inline]
+			fn div_assign(&mut self, rhs: T) {
+				self.0.iter_mut().for_each(|x| *x /= rhs);
+			}
+		}
+
+		impl<$($generics)*> Add for $type {
+			type Output = Self;
+
+			#[inline]
+			fn add(mut self, rhs: Self) -> Self::Output {
+				self += rhs;
+				self
+			}
+		}
+
+		impl<$($generics)*> Sub for $type {
+			type Output = Self;
+
+			#[inline]
+			fn sub(mut self, rhs: Self) -> Self::Output {
+				self -= rhs;
+				self
+			}
+		}
+
+		impl<$($generics)*> Mul<T> for $type {
+			type Output = Self;
+
+			#[inline]
+			fn mul(mut self, rhs: T) -> Self::Output {
+				self *= rhs;
+				self
+			}
+		}
+
+		impl<$($generics)*> Div<T> for $type {
+			type Output = Self;
+
+			#[inline]
+			fn div(mut self, rhs: T) -> Self::Output {
+				self /= rhs;
+				self
+			}
 		}
 
-		impl<T: $type_trait> From<[T; $count]> for $type<T> {
+		impl<$($generics)*> Neg for $type where T: Neg<Output=T> {
+			type Output = Self;
+
+			#[inline]
+			fn neg(mut self) -> Self::Output {
+				self.0.iter_mut().for_each(|x| *x = -*x);
+				self
+			}
+		}
+
+		impl<$($generics)*> Default for $type {
+			fn default() -> Self {
+				Self::all(T::default())
+			}
+		}
+
+		impl<$($generics)*> From<[T; $count]> for $type {
 			fn from(s: [T; $count]) -> Self {
 				Self(s)
 			}
 		}
 
-		impl<T: $type_trait> std::ops::Deref for $type<T> {
+		impl<$($generics)*> std::ops::Deref for $type {
 			type Target = [T; $count];
 
 			fn deref(&self) -> &Self::Target {
@@ -45,23 +164,31 @@ macro_rules! vec_impl {
 			}
 		}
 
-		impl<T: $type_trait> std::ops::DerefMut for $type<T> {
+		impl<$($generics)*> std::ops::DerefMut for $type {
 			fn deref_mut(&mut self) -> &mut Self::Target {
 				&mut self.0
 			}
 		}
-
-		opencv_type_simple_generic! { $type<$type_trait> }
 	};
 }
 
-vec_impl!(Vec2, 2, ValidVecType);
-vec_impl!(Vec3, 3, ValidVecType);
-vec_impl!(Vec4, 4, ValidVecType);
-vec_impl!(Vec6, 6, ValidVecType);
-vec_impl!(Vec8, 8, ValidVecType);
-vec_impl!(Vec18, 18, ValidVecType);
-vec_impl!(Scalar_, 4, ValidScalarType);
+vec_impl!(VecN<T, N>, N, [T: ValidVecType, const N: usize]);
+vec_impl!(Scalar_<T>, 4, [T: ValidScalarType]);
+
+opencv_type_simple_generic! { VecN<ValidVecType, const N> }
+opencv_type_simple_generic! { Scalar_<ValidScalarType> }
+
+impl<T: ValidVecType> Vec3<T> {
+	/// Cross product
+	#[inline]
+	pub fn cross(&self, rhs: &Self) -> Self {
+		Self::from([
+			self[1] * rhs[2] - self[2] * rhs[1],
+			self[2] * rhs[0] - self[0] * rhs[2],
+			self[0] * rhs[1] - self[1] * rhs[0],
+		])
+	}
+}
 
 impl<T: ValidScalarType> Scalar_<T> {
 	pub fn new(v0: T, v1: T, v2: T, v3: T) -> Self {
@@ -10,6 +10,8 @@ use std::{
 
 pub use iter::{VectorIterator, VectorRefIterator};
 pub use vector_extern::{VectorElement, VectorExtern, VectorExternCopyNonBool};
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+pub use vector_half::VectorOff16;
 
 use crate::{
 	platform_types::size_t,
@@ -18,6 +20,8 @@ use crate::{
 };
 
 mod vector_extern;
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+mod vector_half;
 mod iter;
 
 /// Wrapper for C++ [std::vector](https://en.cppreference.com/w/cpp/container/vector)
@@ -176,6 +180,21 @@ impl<T: VectorElement> Vector<T> where Self: VectorExtern<T> {
 	}
 }
 
+#[cfg(feature = "ndarray")]
+impl<T: VectorElement> Vector<T> where Self: VectorExtern<T> + VectorExternCopyNonBool<T> {
+	/// Zero-copy 1-dimensional `ndarray` view into the vector data
+	#[inline]
+	pub fn as_array_view(&self) -> ndarray::ArrayView1<T> {
+		ndarray::ArrayView1::from(self.as_slice())
+	}
+
+	/// Zero-copy mutable 1-dimensional `ndarray` view into the vector data
+	#[inline]
+	pub fn as_array_view_mut(&mut self) -> ndarray::ArrayViewMut1<T> {
+		ndarray::ArrayViewMut1::from(self.as_mut_slice())
+	}
+}
+
 impl<T: VectorElement> Default for Vector<T> where Self: VectorExtern<T> {
 	#[inline]
 	fn default() -> Vector<T> {
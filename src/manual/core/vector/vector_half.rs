@@ -0,0 +1,32 @@
+//! `Vector<f16>` support, enabled by the `half` cargo feature
+//!
+//! `std::vector<cv::float16_t>` is not exposed by the OpenCV API so the bindings for it are not generated, the
+//! corresponding functions are defined in `manual-core.cpp`.
+
+use std::ffi::c_void;
+
+use half::f16;
+
+use crate::{
+	core::Vector,
+	vector_copy_non_bool,
+	vector_extern,
+};
+
+pub type VectorOff16 = Vector<f16>;
+
+vector_extern! { f16, *const c_void, *mut c_void,
+	cv_VectorOff16_new, cv_VectorOff16_delete,
+	cv_VectorOff16_len, cv_VectorOff16_is_empty,
+	cv_VectorOff16_capacity, cv_VectorOff16_shrink_to_fit,
+	cv_VectorOff16_reserve, cv_VectorOff16_remove,
+	cv_VectorOff16_swap, cv_VectorOff16_clear,
+	cv_VectorOff16_get, cv_VectorOff16_set,
+	cv_VectorOff16_push, cv_VectorOff16_insert,
+}
+vector_copy_non_bool! { f16, *const c_void, *mut c_void,
+	cv_VectorOff16_data, cv_VectorOff16_data_mut,
+	cv_VectorOff16_clone,
+}
+
+unsafe impl Send for Vector<f16> {}
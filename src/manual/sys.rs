@@ -12,7 +12,7 @@ use crate::{Error, Result as CrateResult, types::Unit};
 #[repr(C)]
 pub struct Result<S, O = S> {
 	pub error_code: i32,
-	pub error_msg: *mut c_void,
+	pub error: *mut c_void,
 	pub result: S,
 	_p: PhantomData<O>,
 }
@@ -20,10 +20,10 @@ pub struct Result<S, O = S> {
 impl<S: Into<O>, O> Result<S, O> {
 	#[inline]
 	pub fn into_result(self) -> CrateResult<O> {
-		if self.error_msg.is_null() {
+		if self.error.is_null() {
 			Ok(self.result.into())
 		} else {
-			Err(Error::new(self.error_code, unsafe { crate::templ::receive_string(self.error_msg as *mut String) }))
+			Err(unsafe { crate::templ::receive_error(self.error as *mut Error) })
 		}
 	}
 }
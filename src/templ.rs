@@ -1,8 +1,11 @@
 use std::{
+	convert::TryFrom,
 	ffi::CStr,
 	os::raw::c_char,
 };
 
+use crate::{Error, error::ErrorDetails};
+
 macro_rules! extern_container_arg {
 	(nofail mut $name: ident) => {
 		let mut $name = $name.opencv_into_extern_container_nofail();
@@ -37,7 +40,7 @@ macro_rules! callback_arg {
 		unsafe extern "C" fn trampoline($($tr_arg_name: $tr_arg_type),*) -> $tr_ret {
 			let mut callback: Box<Box<dyn FnMut($($fw_arg_type),*) -> $fw_ret + Send + Sync>> = Box::from_raw($tr_userdata_name as _);
 			let out = callback($($fw_arg_name),*);
-			Box::into_raw(callback);
+			let _ = Box::into_raw(callback);
 			out
 		}
 
@@ -101,3 +104,37 @@ pub unsafe fn receive_string(s: *mut String) -> String {
 	}
 	*Box::from_raw(s)
 }
+
+/// Converts nullable C string to `Option`, empty strings are also treated as missing
+unsafe fn opt_string(s: *const c_char) -> Option<String> {
+	if s.is_null() {
+		None
+	} else {
+		Some(CStr::from_ptr(s).to_string_lossy().into_owned())
+			.filter(|s| !s.is_empty())
+	}
+}
+
+#[no_mangle]
+extern "C" fn ocvrs_create_error(code: i32, msg: *const c_char, err: *const c_char, func: *const c_char, file: *const c_char, line: i32, exception_type: *const c_char, wrapper: *const c_char) -> *mut Error {
+	let mut out = Error::new(code, unsafe { opt_string(msg) }.unwrap_or_default());
+	out.details = Some(Box::new(unsafe {
+		ErrorDetails {
+			err: opt_string(err),
+			func: opt_string(func),
+			file: opt_string(file),
+			line: u32::try_from(line).ok().filter(|&line| line > 0),
+			exception_type: opt_string(exception_type),
+			wrapper: opt_string(wrapper),
+		}
+	}));
+	Box::into_raw(Box::new(out))
+}
+
+#[inline]
+pub unsafe fn receive_error(e: *mut Error) -> Error {
+	if e.is_null() {
+		panic!("Got null pointer for receive_error()");
+	}
+	*Box::from_raw(e)
+}
@@ -220,3 +220,6 @@ opencv_type_copy! {
 	isize, usize,
 	*const c_void, *mut c_void,
 }
+
+#[cfg(all(feature = "half", feature = "opencv-4"))]
+opencv_type_copy! { half::f16 }
@@ -1,8 +1,101 @@
 #include "core.hpp"
+#include <memory>
+#include <sstream>
 
 template struct Result<void*>;
 template struct Result<cv::Size>;
 template struct Result<const unsigned char*>;
+template struct Result<unsigned char*>;
+template struct Result<ushort>;
+
+#if CV_VERSION_MAJOR == 3
+	typedef int ocvrs_AccessFlag;
+	typedef int ocvrs_FormatType;
+#else
+	typedef cv::AccessFlag ocvrs_AccessFlag;
+	typedef cv::Formatter::FormatType ocvrs_FormatType;
+#endif
+
+// defined in src/manual/core/mat_allocator.rs
+extern "C" void* ocvrs_mat_allocator_allocate(void* allocator, size_t size);
+extern "C" void ocvrs_mat_allocator_deallocate(void* allocator, void* data, size_t size);
+extern "C" void ocvrs_mat_owned_buffer_free(void* owner);
+
+// Forwards allocation of the Mat data to the Rust implementation of MatAllocator trait
+class OcvrsRustMatAllocator : public cv::MatAllocator {
+public:
+	explicit OcvrsRustMatAllocator(void* allocator) : allocator(allocator) {}
+
+	cv::UMatData* allocate(int dims, const int* sizes, int type, void* data0, size_t* step, ocvrs_AccessFlag, cv::UMatUsageFlags) const CV_OVERRIDE {
+		size_t total = CV_ELEM_SIZE(type);
+		for (int i = dims - 1; i >= 0; i--) {
+			if (step) {
+				if (data0 && step[i] != CV_AUTOSTEP) {
+					CV_Assert(total <= step[i]);
+					total = step[i];
+				} else {
+					step[i] = total;
+				}
+			}
+			total *= sizes[i];
+		}
+		uchar* data = data0 ? (uchar*)data0 : (uchar*)ocvrs_mat_allocator_allocate(allocator, total);
+		if (!data) {
+			CV_Error_(cv::Error::StsNoMem, ("Rust MatAllocator failed to allocate %llu bytes", (unsigned long long)total));
+		}
+		cv::UMatData* u = new cv::UMatData(this);
+		u->data = u->origdata = data;
+		u->size = total;
+		if (data0) {
+			u->flags |= cv::UMatData::USER_ALLOCATED;
+		}
+		return u;
+	}
+
+	bool allocate(cv::UMatData* u, ocvrs_AccessFlag, cv::UMatUsageFlags) const CV_OVERRIDE {
+		return u != NULL;
+	}
+
+	void deallocate(cv::UMatData* u) const CV_OVERRIDE {
+		if (!u) {
+			return;
+		}
+		CV_Assert(u->urefcount == 0);
+		CV_Assert(u->refcount == 0);
+		if (!(u->flags & cv::UMatData::USER_ALLOCATED)) {
+			ocvrs_mat_allocator_deallocate(allocator, u->origdata, u->size);
+			u->origdata = 0;
+		}
+		delete u;
+	}
+
+private:
+	void* allocator;
+};
+
+// Releases the Rust-owned buffer (stored in UMatData::userdata) when the last Mat referencing it is gone
+class OcvrsRustBufferAllocator : public cv::MatAllocator {
+public:
+	cv::UMatData* allocate(int dims, const int* sizes, int type, void* data0, size_t* step, ocvrs_AccessFlag flags, cv::UMatUsageFlags usage_flags) const CV_OVERRIDE {
+		return cv::Mat::getStdAllocator()->allocate(dims, sizes, type, data0, step, flags, usage_flags);
+	}
+
+	bool allocate(cv::UMatData* u, ocvrs_AccessFlag, cv::UMatUsageFlags) const CV_OVERRIDE {
+		return u != NULL;
+	}
+
+	void deallocate(cv::UMatData* u) const CV_OVERRIDE {
+		if (!u) {
+			return;
+		}
+		CV_Assert(u->urefcount == 0);
+		CV_Assert(u->refcount == 0);
+		ocvrs_mat_owned_buffer_free(u->userdata);
+		delete u;
+	}
+};
+
+static OcvrsRustBufferAllocator ocvrs_rust_buffer_allocator;
 
 template<typename T> inline Result<void*> ocvrs_input_array(const T* instance) {
 	try {
@@ -48,18 +141,101 @@ extern "C" {
 		} OCVRS_CATCH(Result_void)
 	}
 
+	Result<void*> cv_manual_Mat_format(const cv::Mat* instance, int fmt, int precision) {
+		try {
+			cv::Ptr<cv::Formatter> formatter = cv::Formatter::get(static_cast<ocvrs_FormatType>(fmt));
+			if (precision >= 0) {
+				formatter->set32fPrecision(precision);
+				formatter->set64fPrecision(precision);
+			}
+			std::ostringstream out;
+			out << formatter->format(*instance);
+			return Ok<void*>(ocvrs_create_string(out.str().c_str()));
+		} OCVRS_CATCH(Result<void*>)
+	}
+
 	Result<const unsigned char*> cv_manual_Mat_data(const cv::Mat* instance) {
 		try {
 			return Ok<const unsigned char*>(instance->data);
 		} OCVRS_CATCH(Result<const unsigned char*>)
 	}
 
+	Result<void*> cv_manual_Mat_from_rust_buffer(int rows, int cols, int type, void* data, size_t size, void* owner) {
+		try {
+			std::unique_ptr<cv::Mat> out(new cv::Mat(rows, cols, type, data));
+			std::unique_ptr<cv::UMatData> u(new cv::UMatData(&ocvrs_rust_buffer_allocator));
+			u->data = u->origdata = (uchar*)data;
+			u->size = size;
+			u->userdata = owner;
+			u->refcount = 1;
+			out->u = u.release();
+			return Ok<void*>(out.release());
+		} OCVRS_CATCH(Result<void*>)
+	}
+
+	void cv_manual_Mat_set_allocator(cv::Mat* instance, cv::MatAllocator* allocator) {
+		instance->allocator = allocator;
+	}
+
+	void cv_manual_Mat_set_default_allocator(cv::MatAllocator* allocator) {
+		cv::Mat::setDefaultAllocator(allocator);
+	}
+
+	Result<void*> cv_manual_RustMatAllocator_new(void* allocator) {
+		try {
+			return Ok<void*>(static_cast<cv::MatAllocator*>(new OcvrsRustMatAllocator(allocator)));
+		} OCVRS_CATCH(Result<void*>)
+	}
+
+	Result<void*> cv_manual_Mat__output_array(cv::Mat* instance, int type) {
+		try {
+			return Ok<void*>(new cv::_OutputArray(cv::_InputArray::FIXED_TYPE + cv::_InputArray::MAT + type + cv::ACCESS_WRITE, instance));
+		} OCVRS_CATCH(Result<void*>)
+	}
+
+	Result<void*> cv_manual_Mat__input_output_array(cv::Mat* instance, int type) {
+		try {
+			return Ok<void*>(new cv::_InputOutputArray(cv::_InputArray::FIXED_TYPE + cv::_InputArray::MAT + type + cv::ACCESS_RW, instance));
+		} OCVRS_CATCH(Result<void*>)
+	}
+
 	Result<cv::Size> cv_manual_UMat_size(const cv::UMat* instance) {
 		try {
 			return Ok<cv::Size>(instance->size());
 		} OCVRS_CATCH(Result<cv::Size>)
 	}
 
+	Result<const unsigned char*> cv_manual_SparseMat_find(const cv::SparseMat* instance, const int* idx) {
+		try {
+			// ptr() doesn't modify the matrix when createMissing is false, that's how SparseMat::find() is implemented too
+			return Ok<const unsigned char*>(const_cast<cv::SparseMat*>(instance)->ptr(idx, false));
+		} OCVRS_CATCH(Result<const unsigned char*>)
+	}
+
+	Result<unsigned char*> cv_manual_SparseMat_ptr_create(cv::SparseMat* instance, const int* idx) {
+		try {
+			return Ok<unsigned char*>(instance->ptr(idx, true));
+		} OCVRS_CATCH(Result<unsigned char*>)
+	}
+
+	Result_void cv_manual_SparseMat_erase(cv::SparseMat* instance, const int* idx) {
+		try {
+			instance->erase(idx);
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	bool cv_manual_SparseMatConstIterator_next(cv::SparseMatConstIterator* instance, const int** idx, const unsigned char** value) {
+		// ptr is reset to NULL when the iterator goes past the last node
+		if (!instance->ptr) {
+			return false;
+		}
+		*idx = instance->node()->idx;
+		*value = instance->ptr;
+		++(*instance);
+		return true;
+	}
+
 	int cv_manual_MatSize_dims(const cv::MatSize* instance) {
 		return *(instance->p - 1);
 	}
@@ -80,6 +256,79 @@ extern "C" {
 		return instance->ptr != instance->sliceEnd;
 	}
 
+	Result_void cv_manual_FileStorage_write_int(cv::FileStorage* instance, const char* name, int val) {
+		try {
+			cv::write(*instance, cv::String(name), val);
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	Result_void cv_manual_FileStorage_write_double(cv::FileStorage* instance, const char* name, double val) {
+		try {
+			cv::write(*instance, cv::String(name), val);
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	Result_void cv_manual_FileStorage_write_string(cv::FileStorage* instance, const char* name, const char* val) {
+		try {
+			cv::write(*instance, cv::String(name), cv::String(val));
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	Result_void cv_manual_FileStorage_write_mat(cv::FileStorage* instance, const char* name, const cv::Mat* val) {
+		try {
+			cv::write(*instance, cv::String(name), *val);
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	Result_void cv_manual_FileStorage_start_struct(cv::FileStorage* instance, const char* name, bool is_seq) {
+		try {
+			// operator<< is used because it tracks the nesting state the same way across all supported OpenCV versions
+			if (*name) {
+				*instance << name;
+			}
+			*instance << (is_seq ? "[" : "{");
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	Result_void cv_manual_FileStorage_end_struct(cv::FileStorage* instance, bool is_seq) {
+		try {
+			*instance << (is_seq ? "]" : "}");
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	Result<void*> cv_manual_FileNode_child(const cv::FileNode* instance, size_t index) {
+		try {
+			// operator[](int) only works for sequences, iterator works for maps too
+			CV_Assert(index < instance->size());
+			cv::FileNodeIterator it = instance->begin();
+			it += (int)index;
+			return Ok<void*>(new cv::FileNode(*it));
+		} OCVRS_CATCH(Result<void*>)
+	}
+
+	Result<void*> cv_manual_FileNodeIterator_next(cv::FileNodeIterator* instance) {
+		try {
+			cv::FileNode* out = new cv::FileNode(**instance);
+			++(*instance);
+			return Ok<void*>(out);
+		} OCVRS_CATCH(Result<void*>)
+	}
+
+	// returns the userdata of the previously installed callback
+	Result<void*> cv_manual_redirectError(cv::ErrorCallback callback, void* userdata) {
+		try {
+			void* prev_userdata = nullptr;
+			cv::redirectError(callback, userdata, &prev_userdata);
+			return Ok<void*>(prev_userdata);
+		} OCVRS_CATCH(Result<void*>)
+	}
+
 	Result<void*> cv_InputArray_input_array(cv::_InputArray* instance) { return ocvrs_input_array(instance); }
 	Result<void*> cv_OutputArray_output_array(cv::_OutputArray* instance) { return ocvrs_output_array(instance); }
 	Result<void*> cv_InputOutputArray_input_output_array(cv::_InputOutputArray* instance) { return ocvrs_input_output_array(instance); }
@@ -108,3 +357,77 @@ extern "C" {
 	ocvrs_matx(Matx44)
 	ocvrs_matx(Matx66)
 }
+
+#if CV_VERSION_MAJOR >= 4
+// std::vector<cv::float16_t> for the `half` feature, elements are passed as raw bits to not depend on how the
+// cv::float16_t struct is passed by value, in memory it's layout compatible with half::f16
+extern "C" {
+	void cv_VectorOff16_delete(std::vector<cv::float16_t>* instance) {
+		delete instance;
+	}
+
+	std::vector<cv::float16_t>* cv_VectorOff16_new() {
+		return new std::vector<cv::float16_t>();
+	}
+
+	size_t cv_VectorOff16_len(const std::vector<cv::float16_t>* instance) {
+		return instance->size();
+	}
+
+	bool cv_VectorOff16_is_empty(const std::vector<cv::float16_t>* instance) {
+		return instance->empty();
+	}
+
+	size_t cv_VectorOff16_capacity(const std::vector<cv::float16_t>* instance) {
+		return instance->capacity();
+	}
+
+	void cv_VectorOff16_shrink_to_fit(std::vector<cv::float16_t>* instance) {
+		instance->shrink_to_fit();
+	}
+
+	void cv_VectorOff16_reserve(std::vector<cv::float16_t>* instance, size_t additional) {
+		instance->reserve(instance->size() + additional);
+	}
+
+	void cv_VectorOff16_remove(std::vector<cv::float16_t>* instance, size_t index) {
+		instance->erase(instance->begin() + index);
+	}
+
+	void cv_VectorOff16_swap(std::vector<cv::float16_t>* instance, size_t index1, size_t index2) {
+		std::swap((*instance)[index1], (*instance)[index2]);
+	}
+
+	void cv_VectorOff16_clear(std::vector<cv::float16_t>* instance) {
+		instance->clear();
+	}
+
+	void cv_VectorOff16_push(std::vector<cv::float16_t>* instance, ushort val) {
+		instance->push_back(cv::float16_t::fromBits(val));
+	}
+
+	void cv_VectorOff16_insert(std::vector<cv::float16_t>* instance, size_t index, ushort val) {
+		instance->insert(instance->begin() + index, cv::float16_t::fromBits(val));
+	}
+
+	Result<ushort> cv_VectorOff16_get(const std::vector<cv::float16_t>* instance, size_t index) {
+		return Ok<ushort>((*instance)[index].bits());
+	}
+
+	void cv_VectorOff16_set(std::vector<cv::float16_t>* instance, size_t index, ushort val) {
+		(*instance)[index] = cv::float16_t::fromBits(val);
+	}
+
+	const cv::float16_t* cv_VectorOff16_data(const std::vector<cv::float16_t>* instance) {
+		return instance->data();
+	}
+
+	cv::float16_t* cv_VectorOff16_data_mut(std::vector<cv::float16_t>* instance) {
+		return instance->data();
+	}
+
+	std::vector<cv::float16_t>* cv_VectorOff16_clone(const std::vector<cv::float16_t>* instance) {
+		return new std::vector<cv::float16_t>(*instance);
+	}
+}
+#endif
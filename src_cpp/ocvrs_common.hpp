@@ -14,34 +14,61 @@
 #endif
 #include <opencv2/core.hpp>
 
+#include <new>
+#include <stdexcept>
+#include <string>
+#include <typeinfo>
+#if defined(__GNUG__) || defined(__clang__)
+	#include <cstdlib>
+	#include <cxxabi.h>
+#endif
+
 #define OCVRS_ONLY_DEPENDENT_TYPES
 // needed to be able to handle commas in the type name in call to OCVRS_CATCH
 #define OCVRS_TYPE(...) __VA_ARGS__
 
 #define CODE_CATCH(return_type, exc_type, code, msg) \
 catch (exc_type) { \
-	return Err<return_type>(code, msg); \
+	return Err<return_type>(code, msg, __func__); \
+}
+
+#define STD_CATCH(return_type, exc_type, code) \
+catch (exc_type& e) { \
+	return Err<return_type>(code, e, __func__); \
 }
 
+// must match the codes of ErrorKind::OutOfMemory, ErrorKind::StdException and ErrorKind::Unknown in src/error.rs
+#define OCVRS_BAD_ALLOC_CODE -99997
+#define OCVRS_STD_EXCEPTION_CODE -99998
+#define OCVRS_UNKNOWN_EXCEPTION_CODE -99999
+
+// more specific exception types must go first, cv::Exception is also derived from std::exception
 #define OCVRS_CATCH(return_type) \
-CODE_CATCH(OCVRS_TYPE(return_type), cv::Exception& e, e.code, e.what()) \
-CODE_CATCH(OCVRS_TYPE(return_type), ..., -99999, "unspecified error in OpenCV guts")
+catch (cv::Exception& e) { \
+	return Err<OCVRS_TYPE(return_type)>(e, __func__); \
+} \
+STD_CATCH(OCVRS_TYPE(return_type), std::bad_alloc, OCVRS_BAD_ALLOC_CODE) \
+STD_CATCH(OCVRS_TYPE(return_type), std::invalid_argument, cv::Error::StsBadArg) \
+STD_CATCH(OCVRS_TYPE(return_type), std::out_of_range, cv::Error::StsOutOfRange) \
+STD_CATCH(OCVRS_TYPE(return_type), std::exception, OCVRS_STD_EXCEPTION_CODE) \
+CODE_CATCH(OCVRS_TYPE(return_type), ..., OCVRS_UNKNOWN_EXCEPTION_CODE, "unspecified error in OpenCV guts")
 
 #define VEC_CATCH(return_type) \
 CODE_CATCH(OCVRS_TYPE(return_type), std::out_of_range, cv::Error::Code::StsOutOfRange, "index out of bounds")
 
 // defined in src/templ.rs
 extern "C" void* ocvrs_create_string(const char*);
+extern "C" void* ocvrs_create_error(int code, const char* msg, const char* err, const char* func, const char* file, int line, const char* exception_type, const char* wrapper);
 
 template<typename T> struct Result {
 	int error_code;
-	void* error_msg;
+	void* error;
 	T result;
 };
 
 struct Result_void {
 	int error_code;
-	void* error_msg;
+	void* error;
 };
 
 template<typename T> inline Result<T> Ok(T result) {
@@ -52,12 +79,56 @@ inline Result_void Ok() {
 	return Result_void { 0, 0 };
 }
 
-template<typename T> inline T Err(int code, const char* msg) {
+// human readable name of the dynamic type, e.g. "std::bad_alloc"
+inline std::string ocvrs_type_name(const std::type_info& type) {
+#if defined(__GNUG__) || defined(__clang__)
+	int status = 0;
+	char* name = abi::__cxa_demangle(type.name(), nullptr, nullptr, &status);
+	if (status == 0 && name) {
+		std::string out(name);
+		std::free(name);
+		return out;
+	}
+#endif
+	return type.name();
+}
+
+template<typename T> inline T Err(int code, void* error) {
 	unsigned char ret_buf[sizeof(T)] = {};
 	T ret = *reinterpret_cast<T*>(&ret_buf);
 	ret.error_code = code;
-	ret.error_msg = ocvrs_create_string(msg);
+	ret.error = error;
 	return ret;
 }
 
+// wrapper is the name of the extern "C" function that caught the exception
+template<typename T> inline T Err(int code, const char* msg, const char* wrapper) {
+	return Err<T>(code, ocvrs_create_error(code, msg, nullptr, nullptr, nullptr, 0, nullptr, wrapper));
+}
+
+// building the strings can throw itself (e.g. under memory pressure), the fallbacks pass only the existing strings
+template<typename T> inline T Err(const cv::Exception& e, const char* wrapper) {
+	try {
+		const std::string type = ocvrs_type_name(typeid(e));
+		return Err<T>(e.code, ocvrs_create_error(e.code, e.what(), e.err.c_str(), e.func.c_str(), e.file.c_str(), e.line, type.c_str(), wrapper));
+	} catch (...) {
+		return Err<T>(e.code, ocvrs_create_error(e.code, e.what(), e.err.c_str(), e.func.c_str(), e.file.c_str(), e.line, typeid(e).name(), wrapper));
+	}
+}
+
+template<typename T> inline T Err(int code, const std::exception& e, const char* wrapper) {
+	try {
+		const std::string type = ocvrs_type_name(typeid(e));
+		const std::string msg = type + ": " + e.what();
+		return Err<T>(code, ocvrs_create_error(code, msg.c_str(), e.what(), nullptr, nullptr, 0, type.c_str(), wrapper));
+	} catch (...) {
+		return Err<T>(code, ocvrs_create_error(code, e.what(), e.what(), nullptr, nullptr, 0, typeid(e).name(), wrapper));
+	}
+}
+
+// out of memory, don't even try to allocate on the C++ side
+template<typename T> inline T Err(int code, const std::bad_alloc& e, const char* wrapper) {
+	return Err<T>(code, ocvrs_create_error(code, e.what(), e.what(), nullptr, nullptr, 0, "std::bad_alloc", wrapper));
+}
+
 #endif
@@ -1,3 +1,8 @@
+use std::{
+    sync::Arc,
+    thread,
+};
+
 use opencv::{
     core::{
         self,
@@ -6,6 +11,8 @@ use opencv::{
         CV_8U,
         CV_MAKETYPE,
         Moments,
+        PCA,
+        PCA_Flags,
         Point2f,
         RotatedRect,
         Scalar,
@@ -118,3 +125,24 @@ fn file_storage() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn pca_sync() -> Result<()> {
+    let data = Mat::from_slice_2d(&[[1., 2.], [2., 4.1], [3., 5.9], [4., 8.]])?;
+    let pca = Arc::new(PCA::new(&data, &core::no_array()?, PCA_Flags::DATA_AS_ROW as i32, 1)?);
+    let sample = Mat::from_slice(&[5., 10.])?;
+    let expected = *pca.project(&sample)?.at_2d::<f64>(0, 0)?;
+    let handles = (0..4)
+        .map(|_| {
+            let pca = Arc::clone(&pca);
+            thread::spawn(move || -> Result<f64> {
+                let sample = Mat::from_slice(&[5., 10.])?;
+                Ok(*pca.project(&sample)?.at_2d::<f64>(0, 0)?)
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert_eq!(expected, handle.join().expect("Thread panicked")?);
+    }
+    Ok(())
+}
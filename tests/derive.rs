@@ -0,0 +1,49 @@
+#![cfg(feature = "derive")]
+
+use opencv::{
+	core::{self, Mat_, Scalar, Vec2f},
+	prelude::*,
+	Result,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, DataType)]
+#[repr(C)]
+struct Bgr {
+	b: u8,
+	g: u8,
+	r: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, DataType)]
+#[repr(C)]
+struct DepthConfidence(f32, f32);
+
+#[derive(Clone, Copy, Debug, PartialEq, DataType)]
+#[repr(C)]
+struct Flow {
+	vec: Vec2f,
+	err: f32,
+}
+
+#[test]
+fn derive_data_type() -> Result<()> {
+	assert_eq!(core::CV_8UC3, Bgr::typ());
+	assert_eq!(core::CV_32FC2, DepthConfidence::typ());
+	assert_eq!(core::CV_32F, Flow::depth());
+	assert_eq!(3, Flow::channels());
+
+	let mut mat = Mat::new_rows_cols_with_default(2, 3, core::CV_8UC3, Scalar::new(1., 2., 3., 0.))?;
+	assert_eq!(Bgr { b: 1, g: 2, r: 3 }, *mat.at_2d::<Bgr>(1, 2)?);
+	mat.at_2d_mut::<Bgr>(0, 1)?.r = 10;
+	assert_eq!(10, mat.at_2d::<core::Vec3b>(0, 1)?[2]);
+	assert!(mat.at_2d::<DepthConfidence>(0, 0).is_err());
+
+	let pairs = [DepthConfidence(1.5, 0.9), DepthConfidence(2., 0.1)];
+	let mat = Mat::from_slice(&pairs)?;
+	assert_eq!(core::CV_32FC2, mat.typ()?);
+	assert_eq!(&pairs, mat.data_typed::<DepthConfidence>()?);
+
+	let mat = Mat_::<Flow>::zeros(2, 2)?;
+	assert_eq!(Flow { vec: Vec2f::all(0.), err: 0. }, *mat.at_2d(1, 1)?);
+	Ok(())
+}
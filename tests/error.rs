@@ -0,0 +1,80 @@
+use opencv::{
+	core::{self, Mat, Scalar},
+	Error,
+	ErrorKind,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn error_opencv_exception() -> Result<()> {
+	let a = Mat::new_rows_cols_with_default(2, 2, core::CV_8U, Scalar::all(1.))?;
+	let b = Mat::new_rows_cols_with_default(3, 3, core::CV_8U, Scalar::all(1.))?;
+	let mut dst = Mat::default()?;
+	let err = core::add(&a, &b, &mut dst, &core::no_array()?, -1).unwrap_err();
+	assert_eq!(core::StsUnmatchedSizes, err.code);
+	assert_eq!(ErrorKind::StsUnmatchedSizes, err.kind());
+	assert!(err.function().map_or(false, |func| func.ends_with("arithm_op")));
+	assert!(err.file().map_or(false, |file| file.ends_with("arithm.cpp")));
+	assert!(err.line().is_some());
+	assert_eq!(Some("cv_add_const__InputArrayR_const__InputArrayR_const__OutputArrayR_const__InputArrayR_int"), err.wrapper());
+	assert!(err.message.contains(err.bare_message()));
+	assert!(err.message.len() > err.bare_message().len());
+	assert_eq!(Some("cv::Exception"), err.exception_type());
+	Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "opencv-32"))]
+fn error_std_exception() {
+	use opencv::dnn;
+
+	// std::string constructor throws std::length_error for the length above max_size() before reading the buffer
+	let err = dnn::read_net_from_darknet_str("", usize::MAX, "", 0).unwrap_err();
+	assert_eq!(ErrorKind::StdException, err.kind());
+	assert!(err.exception_type().map_or(false, |typ| typ.ends_with("length_error")));
+	assert!(!err.bare_message().is_empty());
+	assert!(err.message.starts_with(err.exception_type().unwrap()));
+	assert!(err.message.contains(err.bare_message()));
+	assert_eq!(None, err.function());
+	assert_eq!(Some("cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t"), err.wrapper());
+}
+
+#[test]
+#[cfg(not(feature = "opencv-32"))]
+fn error_bad_alloc() {
+	use opencv::dnn;
+
+	// std::string constructor fails to allocate 2 EiB before reading the buffer
+	let err = dnn::read_net_from_darknet_str("", 1 << 61, "", 0).unwrap_err();
+	assert_eq!(ErrorKind::OutOfMemory, err.kind());
+	assert_ne!(core::StsNoMem, err.code);
+	assert_eq!(Some("std::bad_alloc"), err.exception_type());
+	assert_eq!(Some("cv_dnn_readNetFromDarknet_const_charX_size_t_const_charX_size_t"), err.wrapper());
+}
+
+#[test]
+fn error_rust_side() {
+	let err = Error::new(core::StsBadArg, "bad argument".to_string());
+	assert_eq!(ErrorKind::StsBadArg, err.kind());
+	assert_eq!("bad argument", err.bare_message());
+	assert_eq!(None, err.function());
+	assert_eq!(None, err.file());
+	assert_eq!(None, err.line());
+	assert_eq!(None, err.exception_type());
+	assert_eq!(None, err.wrapper());
+	assert_eq!("bad argument (code: -5)", err.to_string());
+}
+
+#[test]
+fn error_kind_code() {
+	assert_eq!(ErrorKind::StsAssert, ErrorKind::from_code(-215));
+	assert_eq!(core::StsOutOfRange, ErrorKind::StsOutOfRange.code());
+	assert_eq!(ErrorKind::Unknown, ErrorKind::from(-99999));
+	assert_eq!(ErrorKind::StdException, ErrorKind::from_code(ErrorKind::StdException.code()));
+	assert_eq!(ErrorKind::OutOfMemory, ErrorKind::from_code(ErrorKind::OutOfMemory.code()));
+	assert_ne!(ErrorKind::StsNoMem.code(), ErrorKind::OutOfMemory.code());
+	assert_ne!(ErrorKind::Unknown.code(), ErrorKind::StdException.code());
+	assert_eq!(ErrorKind::Other(-12345), ErrorKind::from(-12345));
+	assert_eq!(-12345, i32::from(ErrorKind::Other(-12345)));
+}
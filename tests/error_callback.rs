@@ -0,0 +1,68 @@
+use std::{
+	sync::{Arc, Mutex},
+	thread,
+};
+
+use opencv::{
+	core::{self, ErrorInfo, Mat, Scalar},
+	ErrorKind,
+	prelude::*,
+	Result,
+};
+
+fn fail() -> Result<()> {
+	let a = Mat::new_rows_cols_with_default(2, 2, core::CV_8U, Scalar::all(1.))?;
+	let b = Mat::new_rows_cols_with_default(3, 3, core::CV_8U, Scalar::all(1.))?;
+	let mut dst = Mat::default()?;
+	core::add(&a, &b, &mut dst, &core::no_array()?, -1)
+}
+
+/// The error handler is global so everything is checked within a single test
+#[test]
+fn redirect_error() -> Result<()> {
+	let errors = Arc::new(Mutex::new(Vec::<ErrorInfo>::new()));
+	core::redirect_error(Some(Box::new({
+		let errors = Arc::clone(&errors);
+		move |info| errors.lock().unwrap().push(info.clone())
+	})))?;
+	let err = fail().unwrap_err();
+	{
+		let errors = errors.lock().unwrap();
+		assert_eq!(1, errors.len());
+		assert_eq!(ErrorKind::StsUnmatchedSizes, errors[0].kind());
+		assert_eq!(err.code, errors[0].code);
+		assert_eq!(errors[0].err, err.bare_message());
+		assert_eq!(err.line(), errors[0].line);
+	}
+
+	let threads = (0..4).map(|_| thread::spawn(|| fail().is_err())).collect::<Vec<_>>();
+	for thread in threads {
+		assert!(thread.join().unwrap());
+	}
+	assert_eq!(5, errors.lock().unwrap().len());
+
+	core::silence_errors()?;
+	assert!(fail().is_err());
+	assert_eq!(5, errors.lock().unwrap().len());
+	// the replaced callback is dropped
+	assert_eq!(1, Arc::strong_count(&errors));
+
+	// errors raised from within the callback are not passed to it again instead of deadlocking
+	let nested = Arc::new(Mutex::new(Vec::new()));
+	core::redirect_error(Some(Box::new({
+		let nested = Arc::clone(&nested);
+		move |_| {
+			let inner = fail().is_err();
+			let replaced = core::redirect_error(None).is_ok();
+			nested.lock().unwrap().push((inner, replaced));
+		}
+	})))?;
+	assert!(fail().is_err());
+	assert_eq!(vec![(true, false)], *nested.lock().unwrap());
+
+	core::redirect_error(None)?;
+	assert!(fail().is_err());
+	assert_eq!(1, nested.lock().unwrap().len());
+	assert_eq!(1, Arc::strong_count(&nested));
+	Ok(())
+}
@@ -0,0 +1,96 @@
+use opencv::{
+	core::{self, DMatch, FileStorage, FileStorage_Mode, KeyPoint, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+use matches::assert_matches;
+
+const YAML: &str = "%YAML:1.0
+---
+name: \"camera\"
+index: 3
+scale: 0.5
+flags: [ 1, 0, 1 ]
+labels: [ \"a\", \"b\" ]
+size: { width: 640, height: 480 }
+keypoint: [ 10., 20., 3., 45., 0.5, 1, -1 ]
+match: [ 1, 2, 0, 0.25 ]
+matrix: !!opencv-matrix
+   rows: 2
+   cols: 2
+   dt: i
+   data: [ 1, 2, 3, 4 ]
+";
+
+fn storage() -> Result<FileStorage> {
+	FileStorage::new(YAML, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")
+}
+
+#[test]
+fn file_node_read() -> Result<()> {
+	let fs = storage()?;
+	let root = fs.root(0)?;
+	assert_eq!("camera", root.child("name").unwrap().read::<String>()?);
+	assert_eq!(3, root.child("index").unwrap().read::<u8>()?);
+	assert_eq!(3., root.child("index").unwrap().read::<f64>()?);
+	assert_eq!(0.5, root.child("scale").unwrap().read::<f32>()?);
+	assert_eq!(vec![true, false, true], root.child("flags").unwrap().read::<Vec<bool>>()?);
+	assert_eq!(vec!["a".to_string(), "b".to_string()], root.child("labels").unwrap().read::<Vec<String>>()?);
+
+	let kp = root.child("keypoint").unwrap().read::<KeyPoint>()?;
+	assert_eq!(KeyPoint::new_point(core::Point2f::new(10., 20.), 3., 45., 0.5, 1, -1)?, kp);
+	let m = root.child("match").unwrap().read::<DMatch>()?;
+	assert_eq!(DMatch::new_index(1, 2, 0, 0.25)?, m);
+
+	let mat = root.child("matrix").unwrap().read::<Mat>()?;
+	assert_eq!(vec![vec![1, 2], vec![3, 4]], mat.to_vec_2d::<i32>()?);
+
+	assert!(root.child("missing").is_none());
+	// the generated child access by name is still available
+	assert_eq!("camera", root.get("name")?.read::<String>()?);
+	assert_eq!(None, fs.get("missing")?.read::<Option<i32>>()?);
+	assert_eq!(Some(3), fs.get("index")?.read::<Option<i32>>()?);
+	assert_matches!(fs.get("missing")?.read::<i32>(), Err(Error { code: core::StsObjectNotFound, .. }));
+	assert_matches!(fs.get("name")?.read::<i32>(), Err(Error { code: core::StsParseError, .. }));
+	assert_matches!(fs.get("index")?.read::<String>(), Err(Error { code: core::StsParseError, .. }));
+	assert_matches!(fs.get("match")?.read::<KeyPoint>(), Err(Error { code: core::StsParseError, .. }));
+	Ok(())
+}
+
+#[test]
+fn file_node_index() -> Result<()> {
+	let fs = storage()?;
+	let root = fs.root(0)?;
+	let labels = root.child("labels").unwrap();
+	assert_eq!("b", labels.child(1).unwrap().read::<String>()?);
+	assert!(labels.child(2).is_none());
+	assert!(labels.child("a").is_none());
+	assert_eq!(480, root.child("size").unwrap().child("height").unwrap().read::<i32>()?);
+	assert_eq!(480, root.child("size").unwrap().child(1).unwrap().read::<i32>()?);
+	assert!(root.child("name").unwrap().child(0).is_none());
+	Ok(())
+}
+
+#[test]
+fn file_node_iter() -> Result<()> {
+	let fs = storage()?;
+	let size = fs.get("size")?;
+	let items = size.iter()?
+		.map(|(name, node)| Ok((name, node.read::<i32>()?)))
+		.collect::<Result<Vec<_>>>()?;
+	assert_eq!(vec![("width".to_string(), 640), ("height".to_string(), 480)], items);
+
+	let mut sum = 0;
+	for (name, node) in &fs.get("flags")? {
+		assert!(name.is_empty());
+		sum += node.read::<i32>()?;
+	}
+	assert_eq!(2, sum);
+
+	let root = fs.root(0)?;
+	assert_eq!(9, root.iter()?.len());
+	assert_eq!(0, fs.get("index")?.iter()?.len());
+	Ok(())
+}
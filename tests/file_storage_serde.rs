@@ -0,0 +1,156 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use opencv::{
+	core::{file_storage_serde, FileStorage, FileStorage_Mode, Mat},
+	prelude::*,
+	Result,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+	Fast,
+	Scaled(f64),
+	Window { width: i32, height: i32 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Camera {
+	name: String,
+	index: u8,
+	enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+	threshold: f64,
+	iterations: i32,
+	label: Option<String>,
+	missing: Option<i32>,
+	sizes: Vec<i32>,
+	offset: (i32, i32),
+	cameras: Vec<Camera>,
+	params: BTreeMap<String, f32>,
+	modes: Vec<Mode>,
+}
+
+fn config() -> Config {
+	Config {
+		threshold: 0.75,
+		iterations: 12,
+		label: Some("calibration".to_string()),
+		missing: None,
+		sizes: vec![3, 5, 7],
+		offset: (-4, 8),
+		cameras: vec![
+			Camera { name: "left".to_string(), index: 0, enabled: true },
+			Camera { name: "right".to_string(), index: 1, enabled: false },
+		],
+		params: vec![("alpha".to_string(), 0.5), ("beta".to_string(), 2.)].into_iter().collect(),
+		modes: vec![Mode::Fast, Mode::Scaled(1.5), Mode::Window { width: 640, height: 480 }],
+	}
+}
+
+#[test]
+fn file_storage_serde_memory() -> Result<()> {
+	let config = config();
+	for format in &[".yml", ".xml", ".json"] {
+		let s = file_storage_serde::to_string(&config, format)?;
+		assert!(s.contains("calibration"));
+		assert!(!s.contains("missing"));
+		let read: Config = file_storage_serde::from_str(&s)?;
+		assert_eq!(config, read);
+	}
+	Ok(())
+}
+
+#[test]
+fn file_storage_serde_file() -> Result<()> {
+	let config = config();
+	let path = std::env::temp_dir().join("opencv_rust_file_storage_serde.yml");
+	let path = path.to_str().unwrap();
+	file_storage_serde::to_file(path, &config)?;
+	let read: Config = file_storage_serde::from_file(path)?;
+	std::fs::remove_file(path).unwrap();
+	assert_eq!(config, read);
+	Ok(())
+}
+
+#[test]
+fn file_storage_serde_mat() -> Result<()> {
+	#[derive(Serialize, Deserialize)]
+	struct Calibration {
+		camera_matrix: Mat,
+		error: f64,
+	}
+
+	let calibration = Calibration {
+		camera_matrix: Mat::from_slice_2d(&[[500., 0., 320.], [0., 500., 240.], [0., 0., 1.]])?,
+		error: 0.25,
+	};
+	let s = file_storage_serde::to_string(&calibration, ".yml")?;
+	assert!(s.contains("opencv-matrix"));
+
+	// the matrix is a regular OpenCV node readable without serde
+	let fs = FileStorage::new(&s, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+	let camera_matrix = fs.get("camera_matrix")?.mat()?;
+	assert_eq!(f64::typ(), camera_matrix.typ()?);
+	assert_eq!(320., *camera_matrix.at_2d::<f64>(0, 2)?);
+
+	let read: Calibration = file_storage_serde::from_str(&s)?;
+	assert_eq!(calibration.camera_matrix.to_vec_2d::<f64>()?, read.camera_matrix.to_vec_2d::<f64>()?);
+	assert_eq!(0.25, read.error);
+	Ok(())
+}
+
+#[test]
+fn file_storage_serde_struct_named_mat() -> Result<()> {
+	// user struct with the same name as the OpenCV matrix is a regular mapping
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Mat {
+		rows: i32,
+		name: String,
+	}
+
+	let mat = Mat { rows: 3, name: "user".to_string() };
+	let s = file_storage_serde::to_string(&mat, ".yml")?;
+	assert!(!s.contains("opencv-matrix"));
+	let read: Mat = file_storage_serde::from_str(&s)?;
+	assert_eq!(mat, read);
+
+	// even with the same field names
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	#[serde(rename = "Mat")]
+	struct MatLike {
+		rows: i32,
+		cols: i32,
+		#[serde(rename = "type")]
+		typ: i32,
+		data: String,
+	}
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Wrapper {
+		mat: MatLike,
+	}
+
+	let wrapper = Wrapper { mat: MatLike { rows: 1, cols: 2, typ: 0, data: "user".to_string() } };
+	let s = file_storage_serde::to_string(&wrapper, ".yml")?;
+	assert!(!s.contains("opencv-matrix"));
+	let read: Wrapper = file_storage_serde::from_str(&s)?;
+	assert_eq!(wrapper, read);
+	Ok(())
+}
+
+#[test]
+fn file_storage_serde_errors() -> Result<()> {
+	assert!(file_storage_serde::to_string(&5, ".yml").is_err());
+	assert!(file_storage_serde::to_string(&vec![1, 2], ".yml").is_err());
+	let too_big: BTreeMap<_, _> = vec![("v", u64::MAX)].into_iter().collect();
+	assert!(file_storage_serde::to_string(&too_big, ".yml").is_err());
+	assert!(file_storage_serde::from_str::<Camera>("%YAML:1.0\n---\nname: left\n").is_err());
+	Ok(())
+}
@@ -0,0 +1,46 @@
+#![cfg(all(feature = "half", feature = "opencv-4"))]
+
+use half::f16;
+
+use opencv::{
+	core::{self, Mat_, Vec3, Vector},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn half_mat() -> Result<()> {
+	let src = [f16::from_f32(1.), f16::from_f32(-2.5), f16::from_f32(0.125)];
+	let mat = Mat::from_slice(&src)?;
+	assert_eq!(core::CV_16F, mat.depth()?);
+	assert_eq!(core::CV_16FC1, mat.typ()?);
+	assert_eq!(&src, mat.data_typed::<f16>()?);
+
+	let mut mat_f32 = Mat::default()?;
+	mat.convert_to(&mut mat_f32, core::CV_32F, 1., 0.)?;
+	assert_eq!(&[1., -2.5, 0.125], mat_f32.data_typed::<f32>()?);
+
+	let mut mat_f16 = Mat::default()?;
+	mat_f32.convert_to(&mut mat_f16, core::CV_16F, 2., 0.)?;
+	assert_eq!(f16::from_f32(-5.), *mat_f16.at::<f16>(1)?);
+
+	let mat = Mat_::<Vec3<f16>>::zeros(2, 2)?;
+	assert_eq!(core::CV_16FC3, mat.typ()?);
+	assert_eq!(Vec3::all(f16::from_f32(0.)), *mat.at_2d(1, 1)?);
+	Ok(())
+}
+
+#[test]
+fn half_vector() -> Result<()> {
+	let src = vec![f16::from_f32(0.5), f16::from_f32(1.5), f16::from_f32(-3.)];
+	let mut vec = Vector::<f16>::from_iter(src.iter().copied());
+	assert_eq!(3, vec.len());
+	assert_eq!(f16::from_f32(1.5), vec.get(1)?);
+	assert_eq!(&src[..], vec.as_slice());
+
+	vec.set(0, f16::from_f32(2.))?;
+	vec.push(f16::from_f32(4.));
+	assert_eq!(vec![f16::from_f32(2.), f16::from_f32(1.5), f16::from_f32(-3.), f16::from_f32(4.)], vec.to_vec());
+	assert_eq!(vec.to_vec(), vec.clone().to_vec());
+	Ok(())
+}
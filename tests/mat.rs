@@ -1,14 +1,26 @@
-use std::ffi::c_void;
+use std::{
+	alloc,
+	ffi::c_void,
+	sync::{
+		Arc,
+		atomic::{AtomicUsize, Ordering},
+	},
+};
 
 use matches::assert_matches;
 
 use opencv::{
-	core::{self, MatConstIterator, Point, Rect, Scalar, Size, Vec2b, Vec3d, Vec3f, Vec4w},
+	core::{self, Mat_, Mat_AUTO_STEP, MatAllocator, MatAllocatorHandle, MatConstIterator, Point, Range, Rect, Scalar, Size, Vec2b, Vec3d, Vec3f, Vec4w, Vector},
 	Error,
 	prelude::*,
 	Result,
+	s,
 	types::{VectorOfi32, VectorOfMat},
 };
+#[cfg(not(feature = "opencv-4"))]
+use opencv::core::{Formatter_FMT_CSV as FMT_CSV, Formatter_FMT_DEFAULT as FMT_DEFAULT, Formatter_FMT_PYTHON as FMT_PYTHON};
+#[cfg(feature = "opencv-4")]
+use opencv::core::Formatter_FormatType::{FMT_CSV, FMT_DEFAULT, FMT_PYTHON};
 
 const PIXEL: &[u8] = include_bytes!("pixel.png");
 
@@ -487,6 +499,59 @@ fn mat_iterator() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_iter() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[1u16, 2, 3], [4, 5, 6], [7, 8, 9]])?;
+	{
+		let mut iter = mat.iter::<u16>()?;
+		assert_eq!(9, iter.len());
+		assert_eq!(Some((Point::new(0, 0), &1)), iter.next());
+		assert_eq!(Some((Point::new(1, 0), &2)), iter.next());
+		assert_eq!(7, iter.len());
+		assert_eq!(Some((Point::new(2, 2), &9)), iter.last());
+	}
+	assert_matches!(mat.iter::<u8>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	{
+		let mut roi = mat.roi_mut(Rect::new(1, 1, 2, 2))?;
+		assert!(!roi.is_continuous()?);
+		let elems = roi.iter::<u16>()?.map(|(pt, &x)| (pt.x, pt.y, x)).collect::<Vec<_>>();
+		assert_eq!(vec![(0, 0, 5), (1, 0, 6), (0, 1, 8), (1, 1, 9)], elems);
+		for (pt, x) in roi.iter_mut::<u16>()? {
+			*x += (pt.y * 10) as u16;
+		}
+		let rows = roi.iter_rows::<u16>()?.collect::<Vec<_>>();
+		assert_eq!(vec![&[5, 6][..], &[18, 19][..]], rows);
+	}
+	for row in mat.iter_rows_mut::<u16>()? {
+		row[0] = 0;
+	}
+	assert_eq!(vec![vec![0, 2, 3], vec![0, 5, 6], vec![0, 18, 19]], mat.to_vec_2d::<u16>()?);
+	assert_eq!(0, Mat::default()?.iter::<u8>()?.count());
+	Ok(())
+}
+
+#[test]
+fn mat_ops() -> Result<()> {
+	let a = Mat::from_slice_2d(&[[1f64, 2.], [3., 4.]])?;
+	let b = Mat::from_slice_2d(&[[10f64, 20.], [30., 40.]])?;
+	let res = (&a + &b * 0.5).to_mat()?;
+	assert_eq!(vec![vec![6., 12.], vec![18., 24.]], res.to_vec_2d::<f64>()?);
+	let res = (-&a + Scalar::all(1.)).to_mat()?;
+	assert_eq!(vec![vec![0., -1.], vec![-2., -3.]], res.to_vec_2d::<f64>()?);
+	// MatExpr * Mat is a matrix multiplication
+	let res = ((&b - &a) / 2. * &a).to_mat()?;
+	assert_eq!(vec![vec![31.5, 45.], vec![67.5, 99.]], res.to_vec_2d::<f64>()?);
+	let res = (&b / &a - 120. / &b).to_mat()?;
+	assert_eq!(vec![vec![-2., 4.], vec![6., 7.]], res.to_vec_2d::<f64>()?);
+	let expr = (&a * 2.).into_result()?;
+	let res = (Scalar::all(1.) - &expr - &a).to_mat()?;
+	assert_eq!(vec![vec![-2., -5.], vec![-8., -11.]], res.to_vec_2d::<f64>()?);
+
+	let c = Mat::from_slice_2d(&[[1f64, 2., 3.]])?;
+	assert!((&a + &c + &b).to_mat().is_err());
+	Ok(())
+}
+
 #[test]
 fn mat_locate_roi() -> Result<()> {
 	let mat = Mat::from_slice(&[1, 2, 3, 4])?;
@@ -499,6 +564,146 @@ fn mat_locate_roi() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_roi_ref() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]])?;
+	{
+		let roi = mat.roi_ref(Rect::new(1, 1, 2, 2))?;
+		assert!(roi.is_submatrix()?);
+		assert_eq!(Size::new(2, 2), roi.size()?);
+		assert_eq!(5, *roi.at_2d::<i32>(0, 0)?);
+		assert_eq!(9, *roi.at_2d::<i32>(1, 1)?);
+		assert_eq!(2, roi.rows());
+		let inner = roi.roi(Rect::new(1, 0, 1, 2))?;
+		assert_eq!(vec![vec![6], vec![9]], inner.to_vec_2d::<i32>()?);
+		// several read-only views of the same Mat can be alive at the same time
+		let other = mat.roi_ref(Rect::new(0, 0, 1, 1))?;
+		assert_eq!(1, *other.at::<i32>(0)?);
+		let copy = roi.to_mat()?;
+		assert!(!copy.is_submatrix()?);
+		assert_eq!(vec![vec![5, 6], vec![8, 9]], copy.to_vec_2d::<i32>()?);
+	}
+	{
+		let mut roi = mat.roi_mut(Rect::new(0, 0, 2, 1))?;
+		roi.set(Scalar::from(10.))?;
+		*roi.at_2d_mut::<i32>(0, 1)? = 11;
+	}
+	assert_eq!(vec![vec![10, 11, 3], vec![4, 5, 6], vec![7, 8, 9]], mat.to_vec_2d::<i32>()?);
+	{
+		let (mut top, mut bottom) = mat.split_at_row_mut(1)?;
+		assert_eq!(Size::new(3, 1), top.size()?);
+		assert_eq!(Size::new(3, 2), bottom.size()?);
+		top.set(Scalar::from(1.))?;
+		bottom.set(Scalar::from(2.))?;
+	}
+	assert_eq!(vec![vec![1, 1, 1], vec![2, 2, 2], vec![2, 2, 2]], mat.to_vec_2d::<i32>()?);
+	{
+		let (left, right) = mat.split_at_col_mut(1)?;
+		assert_eq!(Size::new(1, 3), left.size()?);
+		assert_eq!(Size::new(2, 3), right.size()?);
+	}
+	assert_matches!(mat.split_at_row_mut(4), Err(Error { code: core::StsOutOfRange, .. }));
+	{
+		let mut ranges = Vector::<Range>::new();
+		ranges.push(Range::new(1, 3)?);
+		ranges.push(Range::all()?);
+		let rows = mat.ranges_ref(&ranges)?;
+		assert_eq!(Size::new(3, 2), rows.size()?);
+		let cols = mat.rowscols_ref(&Range::all()?, &Range::new(2, 3)?)?;
+		assert_eq!(Size::new(1, 3), cols.size()?);
+		assert_eq!(2, *cols.at_2d::<i32>(2, 0)?);
+	}
+	Ok(())
+}
+
+#[test]
+fn mat_from_slice_ref() -> Result<()> {
+	{
+		let data = [1u8, 2, 3, 4, 5, 6];
+		let mat = Mat::from_slice_ref(&data)?;
+		assert_eq!(u8::typ(), mat.typ()?);
+		assert_eq!(Size::new(6, 1), mat.size()?);
+		assert_eq!(data.as_ptr(), mat.data()? as *const u8);
+		assert_eq!(&data, mat.data_typed::<u8>()?);
+	}
+	{
+		let data = [1f32, 2., 3., 4., 5., 6.];
+		let mat = Mat::new_rows_cols_with_slice(2, 3, &data, Mat_AUTO_STEP)?;
+		assert_eq!(Size::new(3, 2), mat.size()?);
+		assert_eq!(vec![vec![1., 2., 3.], vec![4., 5., 6.]], mat.to_vec_2d::<f32>()?);
+		let roi = mat.roi(Rect::new(1, 1, 2, 1))?;
+		assert_eq!(vec![vec![5., 6.]], roi.to_vec_2d::<f32>()?);
+		let copy = roi.to_mat()?;
+		assert_ne!(data.as_ptr(), copy.data()? as *const _ as *const f32);
+		assert_eq!(vec![vec![5., 6.]], copy.to_vec_2d::<f32>()?);
+		assert_matches!(Mat::new_rows_cols_with_slice(3, 3, &data, Mat_AUTO_STEP), Err(Error { code: core::StsUnmatchedSizes, .. }));
+		assert_matches!(Mat::new_rows_cols_with_slice(2, 2, &data, 7), Err(Error { code: core::StsBadArg, .. }));
+	}
+	{
+		let mut data = [1u16, 2, 3, 4, 5, 6, 7, 8];
+		{
+			// 2 columns out of 4, step includes 2 padding elements
+			let mut mat = Mat::new_rows_cols_with_slice_mut(2, 2, &mut data, 4 * 2)?;
+			assert!(!mat.is_continuous()?);
+			assert_eq!(vec![vec![1, 2], vec![5, 6]], mat.to_vec_2d::<u16>()?);
+			mat.set(Scalar::from(9.))?;
+		}
+		assert_eq!([9, 9, 3, 4, 9, 9, 7, 8], data);
+	}
+	{
+		let mut data = [0i32; 3];
+		let mut mat = Mat::from_slice_mut(&mut data)?;
+		core::add(&Mat::from_slice(&[1, 2, 3])?, &Mat::from_slice(&[10, 20, 30])?, &mut mat, &core::no_array()?, -1)?;
+		drop(mat);
+		assert_eq!([11, 22, 33], data);
+	}
+	Ok(())
+}
+
+#[test]
+fn mat_from_vec() -> Result<()> {
+	let v = vec![1., 2., 3., 4., 5., 6.];
+	let data_ptr = v.as_ptr();
+	let mat = Mat::from_vec(v, 2, 3)?;
+	assert_eq!(f64::typ(), mat.typ()?);
+	assert_eq!(Size::new(3, 2), mat.size()?);
+	assert_eq!(data_ptr, mat.data()? as *const _ as *const f64);
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 2, 1))?;
+	drop(mat);
+	assert_eq!(vec![vec![5., 6.]], roi.to_vec_2d::<f64>()?);
+	assert_matches!(Mat::from_vec(vec![1u8, 2, 3], 2, 2), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	Ok(())
+}
+
+#[test]
+fn mat_allocator() -> Result<()> {
+	struct CountingAllocator(Arc<AtomicUsize>);
+
+	impl MatAllocator for CountingAllocator {
+		fn allocate(&self, size: usize) -> *mut u8 {
+			self.0.fetch_add(size, Ordering::SeqCst);
+			unsafe { alloc::alloc(alloc::Layout::from_size_align(size, 64).unwrap()) }
+		}
+
+		unsafe fn deallocate(&self, data: *mut u8, size: usize) {
+			self.0.fetch_sub(size, Ordering::SeqCst);
+			alloc::dealloc(data, alloc::Layout::from_size_align(size, 64).unwrap())
+		}
+	}
+
+	let allocated = Arc::new(AtomicUsize::new(0));
+	let allocator = MatAllocatorHandle::new(CountingAllocator(Arc::clone(&allocated)))?;
+	let mut mat = Mat::default()?;
+	mat.set_allocator(Some(allocator));
+	unsafe { mat.create_rows_cols(10, 20, u16::typ()) }?;
+	assert_eq!(10 * 20 * 2, allocated.load(Ordering::SeqCst));
+	mat.set(Scalar::from(3.))?;
+	assert_eq!(3, *mat.at_2d::<u16>(9, 19)?);
+	drop(mat);
+	assert_eq!(0, allocated.load(Ordering::SeqCst));
+	Ok(())
+}
+
 #[test]
 fn mat_convert() -> Result<()> {
 	let mat = Mat::from_slice(&[1, 2, 3, 4])?;
@@ -513,6 +718,43 @@ fn mat_convert() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_typed() -> Result<()> {
+	let mut mat = Mat_::<Vec3f>::zeros(3, 4)?;
+	assert_eq!(Vec3f::typ(), mat.typ()?);
+	assert_eq!(Size::new(4, 3), mat.size()?);
+	assert_eq!(Vec3f::all(0.), *mat.at_2d(2, 3)?);
+	*mat.at_pt_mut(Point::new(3, 2))? = Vec3f::from([1., 2., 3.]);
+	assert_eq!(Vec3f::from([1., 2., 3.]), *mat.at_2d(2, 3)?);
+	assert_eq!(4, mat.at_row(2)?.len());
+	assert_matches!(mat.at_2d(3, 0), Err(Error { code: core::StsOutOfRange, .. }));
+
+	let roi = mat.roi(Rect::new(2, 1, 2, 2))?;
+	assert_eq!(Vec3f::from([1., 2., 3.]), *roi.at_2d(1, 1)?);
+	let copy = roi.to_mat()?;
+	assert!(!copy.is_submatrix()?);
+	assert_eq!(vec![vec![Vec3f::all(0.), Vec3f::all(0.)], vec![Vec3f::all(0.), Vec3f::from([1., 2., 3.])]], copy.to_vec_2d()?);
+	drop(roi);
+	{
+		let mut roi = mat.roi_mut(Rect::new(0, 0, 2, 1))?;
+		*roi.at_2d_mut(0, 1)? = Vec3f::all(5.);
+		assert_eq!(2, roi.at_row(0)?.len());
+	}
+	assert_eq!(Vec3f::all(5.), *mat.at_2d(0, 1)?);
+
+	let mut mat = Mat_::<u8>::from_slice_2d(&[[2, 4], [6, 8]])?;
+	core::add_weighted(&mat.clone(), 0.5, &mat.clone(), 0., 0., &mut mat, -1)?;
+	assert_eq!(vec![vec![1, 2], vec![3, 4]], mat.to_vec_2d()?);
+	// output type of Mat_ is fixed
+	assert!(core::add_weighted(&mat.clone(), 0.5, &mat.clone(), 0., 0., &mut mat, f64::typ()).is_err());
+	assert_eq!(u8::typ(), mat.typ()?);
+
+	let nd = Mat_::<i32>::zeros_nd(&[2, 3, 4])?;
+	assert_eq!(0, *nd.at_3d(1, 2, 3)?);
+	assert_eq!(0, *nd.at_nd(&[1, 1, 1])?);
+	Ok(())
+}
+
 #[test]
 fn mat_mul() -> Result<()> {
 	{
@@ -543,3 +785,59 @@ fn mat_mul() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[test]
+fn mat_display() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1i32, 2], [3, 4]])?;
+	assert_eq!("[1, 2;\n 3, 4]", mat.to_string());
+	assert_eq!("[[1, 2],\n [3, 4]]", mat.display_as(FMT_PYTHON).to_string());
+	assert!(format!("{:#}", mat).starts_with("array([[1, 2],"));
+	assert_eq!("[1, 2]\n... showing 1x2 of 2x2 elements", mat.display_as(FMT_DEFAULT).limit(1, 5).to_string());
+
+	let typed = mat.try_clone()?.try_into_typed::<i32>()?;
+	assert_eq!(mat.to_string(), typed.to_string());
+
+	let mat = Mat::from_slice_2d(&[[1.23456f64]])?;
+	// precision sets the significant digits like in OpenCV
+	assert_eq!("[1.23]", format!("{:.3}", mat));
+
+	let mat = Mat::new_rows_cols_with_default(40, 40, i32::typ(), Scalar::all(0.))?;
+	assert!(mat.to_string().ends_with("\n... showing 32x32 of 40x40 elements"));
+	assert!(!mat.display_as(FMT_CSV).unlimited().to_string().contains("showing"));
+
+	let nd = Mat_::<u8>::zeros_nd(&[2, 3, 4])?;
+	assert_eq!("<3-dimensional Mat with 24 elements>", nd.to_string());
+	Ok(())
+}
+
+#[test]
+fn mat_slice() -> Result<()> {
+	let mut mat = Mat::new_nd_with_default(&[4, 6, 3], i32::typ(), Scalar::all(0.))?;
+	assert_eq!(&[4, 6, 3], mat.shape());
+	{
+		let part = mat.slice(&s![.., 2..5, ..])?;
+		assert_eq!(&[4, 3, 3], part.shape());
+		let part = mat.slice(&s![1, 3.., ..2])?;
+		assert_eq!(&[1, 3, 2], part.shape());
+		let part = mat.slice(&s![..=1, 5, 0..3])?;
+		assert_eq!(&[2, 1, 3], part.shape());
+	}
+	{
+		let mut part = mat.slice_mut(&s![2, 1..3, 1])?;
+		part.set(Scalar::all(7.))?;
+	}
+	assert_eq!(7, *mat.at_3d::<i32>(2, 1, 1)?);
+	assert_eq!(7, *mat.at_3d::<i32>(2, 2, 1)?);
+	assert_eq!(0, *mat.at_3d::<i32>(2, 3, 1)?);
+	assert_eq!(0, *mat.at_3d::<i32>(1, 1, 1)?);
+	assert_matches!(mat.slice(&s![.., ..]), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	assert_matches!(mat.slice(&s![.., 4..7, ..]), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_matches!(mat.slice(&s![4, .., ..]), Err(Error { code: core::StsOutOfRange, .. }));
+	{
+		let flat = mat.reshape_ref(&[12, 6])?;
+		assert_eq!(&[12, 6], flat.shape());
+		assert_eq!(7, *flat.at_2d::<i32>(6, 4)?);
+	}
+	assert!(mat.reshape_ref(&[5, 5]).is_err());
+	Ok(())
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "image")]
+
+use image::{GrayImage, ImageBuffer, Luma, Rgb, Rgba, RgbaImage, RgbImage};
+use matches::assert_matches;
+
+use opencv::{
+	core::{self, ChannelOrder, Rect, Vec3b, Vec3f, Vec4b},
+	Error,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn mat_from_image() -> Result<()> {
+	let img = RgbImage::from_fn(4, 3, |x, y| Rgb([x as u8, y as u8, 100]));
+	let mat = Mat::from_image(&img, ChannelOrder::Bgr)?;
+	assert_eq!(Vec3b::typ(), mat.typ()?);
+	assert_eq!(3, mat.rows());
+	assert_eq!(4, mat.cols());
+	assert_eq!(Vec3b::from([100, 2, 3]), *mat.at_2d::<Vec3b>(2, 3)?);
+
+	let mat = Mat::from_image(&img, ChannelOrder::Rgb)?;
+	assert_eq!(Vec3b::from([3, 2, 100]), *mat.at_2d::<Vec3b>(2, 3)?);
+
+	let img = RgbaImage::from_pixel(2, 2, Rgba([1, 2, 3, 4]));
+	let mat = Mat::from_image(&img, ChannelOrder::Bgr)?;
+	assert_eq!(Vec4b::from([3, 2, 1, 4]), *mat.at_2d::<Vec4b>(1, 1)?);
+
+	let img = ImageBuffer::<Luma<u16>, _>::from_fn(3, 2, |x, y| Luma([(x * 1000 + y) as u16]));
+	let mat = Mat::from_image(&img, ChannelOrder::Bgr)?;
+	assert_eq!(u16::typ(), mat.typ()?);
+	assert_eq!(2001, *mat.at_2d::<u16>(1, 2)?);
+
+	let img = ImageBuffer::<Rgb<f32>, _>::from_pixel(1, 1, Rgb([0.1, 0.2, 0.3]));
+	let mat = Mat::from_image(&img, ChannelOrder::Bgr)?;
+	assert_eq!(Vec3f::from([0.3, 0.2, 0.1]), *mat.at_2d::<Vec3f>(0, 0)?);
+	Ok(())
+}
+
+#[test]
+fn mat_from_image_ref() -> Result<()> {
+	let mut img = GrayImage::from_fn(5, 4, |x, y| Luma([(y * 5 + x) as u8]));
+	{
+		let (mat, order) = Mat::from_image_ref(&img)?;
+		assert_eq!(ChannelOrder::Rgb, order);
+		assert_eq!(img.as_raw().as_ptr(), mat.data()? as *const u8);
+		assert_eq!(13, *mat.at_2d::<u8>(2, 3)?);
+	}
+	{
+		let (mut mat, _) = Mat::from_image_mut(&mut img)?;
+		*mat.at_2d_mut::<u8>(0, 0)? = 200;
+	}
+	assert_eq!(Luma([200]), *img.get_pixel(0, 0));
+
+	let mut img = RgbImage::from_pixel(2, 2, Rgb([1, 2, 3]));
+	{
+		let (mat, order) = Mat::from_image_ref(&img)?;
+		assert_eq!(ChannelOrder::Rgb, order);
+		assert_eq!(Vec3b::from([1, 2, 3]), *mat.at_2d::<Vec3b>(1, 1)?);
+		assert_eq!(Rgb([3, 2, 1]), *mat.to_image::<Rgb<u8>>(ChannelOrder::Bgr)?.get_pixel(1, 1));
+		assert_eq!(Rgb([1, 2, 3]), *mat.to_image::<Rgb<u8>>(order)?.get_pixel(1, 1));
+	}
+	{
+		let (mut mat, order) = Mat::from_image_mut(&mut img)?;
+		assert_eq!(ChannelOrder::Rgb, order);
+		*mat.at_2d_mut::<Vec3b>(0, 1)? = Vec3b::from([4, 5, 6]);
+	}
+	assert_eq!(Rgb([4, 5, 6]), *img.get_pixel(1, 0));
+	Ok(())
+}
+
+#[test]
+fn mat_to_image() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[
+		[Vec3b::from([1, 2, 3]), Vec3b::from([4, 5, 6]), Vec3b::from([7, 8, 9])],
+		[Vec3b::from([10, 11, 12]), Vec3b::from([13, 14, 15]), Vec3b::from([16, 17, 18])],
+	])?;
+	let img = mat.to_image::<Rgb<u8>>(ChannelOrder::Bgr)?;
+	assert_eq!((3, 2), img.dimensions());
+	assert_eq!(Rgb([6, 5, 4]), *img.get_pixel(1, 0));
+	let img = mat.to_image::<Rgb<u8>>(ChannelOrder::Rgb)?;
+	assert_eq!(Rgb([4, 5, 6]), *img.get_pixel(1, 0));
+
+	let roi = mat.roi_ref(Rect::new(1, 0, 2, 2))?;
+	let img = roi.to_image::<Rgb<u8>>(ChannelOrder::Rgb)?;
+	assert_eq!((2, 2), img.dimensions());
+	assert_eq!(Rgb([16, 17, 18]), *img.get_pixel(1, 1));
+	assert_matches!(roi.as_image::<Rgb<u8>>(), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	drop(roi);
+
+	assert_eq!(Rgb([13, 14, 15]), *mat.as_image::<Rgb<u8>>()?.get_pixel(1, 1));
+	mat.as_image_mut::<Rgb<u8>>()?.put_pixel(2, 1, Rgb([0, 0, 0]));
+	assert_eq!(Vec3b::all(0), *mat.at_2d::<Vec3b>(1, 2)?);
+
+	assert_matches!(mat.to_image::<Luma<u8>>(ChannelOrder::Bgr), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "ndarray")]
+
+use matches::assert_matches;
+use ndarray::{arr2, Array, Array3, Axis, s};
+
+use opencv::{
+	core::{self, Rect, Scalar, Vec3b, Vector},
+	Error,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn mat_array_view() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[[1i32, 2, 3], [4, 5, 6], [7, 8, 9]])?;
+	assert_eq!(arr2(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]]), mat.as_array_view::<i32>()?);
+	assert_matches!(mat.as_array_view::<u8>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+
+	mat.as_array_view_mut::<i32>()?.column_mut(1).fill(0);
+	assert_eq!(vec![vec![1, 0, 3], vec![4, 0, 6], vec![7, 0, 9]], mat.to_vec_2d::<i32>()?);
+	{
+		let roi = mat.roi_ref(Rect::new(1, 1, 2, 2))?;
+		let view = roi.as_array_view::<i32>()?;
+		assert_eq!(&[3, 1], view.strides());
+		assert_eq!(arr2(&[[0, 6], [0, 9]]), view);
+		assert_eq!(arr2(&[[0, 6], [0, 9]]), view.to_owned());
+	}
+
+	let empty = Mat::default()?;
+	assert_eq!((0, 0), empty.as_array_view::<u8>()?.dim());
+	Ok(())
+}
+
+#[test]
+fn mat_array_view_channels() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(2, 3, Vec3b::typ(), Scalar::new(1., 2., 3., 0.))?;
+	let view = mat.as_array_view_channels::<u8>()?;
+	assert_eq!((2, 3, 3), view.dim());
+	assert_eq!(&[9, 3, 1], view.strides());
+	assert_eq!(vec![1, 2, 3], view.slice(s![1, 2, ..]).to_vec());
+	assert_eq!(Vec3b::from([1, 2, 3]), mat.as_array_view::<Vec3b>()?[(1, 1)]);
+	assert_matches!(mat.as_array_view_channels::<u16>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+
+	mat.as_array_view_channels_mut::<u8>()?.index_axis_mut(Axis(2), 0).fill(10);
+	assert_eq!(Vec3b::from([10, 2, 3]), *mat.at_2d::<Vec3b>(0, 2)?);
+	Ok(())
+}
+
+#[test]
+fn mat_array_view_nd() -> Result<()> {
+	let mut mat = Mat::new_nd_with_default(&[2, 3, 4], f32::typ(), Scalar::all(1.))?;
+	{
+		let mut view = mat.as_array_view_nd_mut::<f32>()?;
+		assert_eq!(&[2, 3, 4], view.shape());
+		view[[1, 2, 3]] = 5.;
+	}
+	assert_eq!(5., *mat.at_3d::<f32>(1, 2, 3)?);
+	assert_eq!(29., mat.as_array_view_nd::<f32>()?.sum());
+	Ok(())
+}
+
+#[test]
+fn mat_from_array() -> Result<()> {
+	let arr = Array::from_shape_fn((3, 4), |(row, col)| (row * 4 + col) as u16);
+	let mat = Mat::from_array(&arr)?;
+	assert_eq!(u16::typ(), mat.typ()?);
+	assert_eq!(3, mat.rows());
+	assert_eq!(4, mat.cols());
+	assert_eq!(11, *mat.at_2d::<u16>(2, 3)?);
+
+	// non-standard layout
+	let mat = Mat::from_array(&arr.t())?;
+	assert_eq!(4, mat.rows());
+	assert_eq!(3, mat.cols());
+	assert_eq!(arr.t(), mat.as_array_view::<u16>()?);
+
+	let mat = Mat::from_array(&Array::from(vec![1., 2., 3.]))?;
+	assert_eq!(1, mat.rows());
+	assert_eq!(vec![1., 2., 3.], mat.data_typed::<f64>()?.to_vec());
+
+	let arr = Array::from_shape_fn((2, 3, 4, 5), |(a, b, c, d)| (a + b + c + d) as i32);
+	let mat = Mat::from_array(&arr)?;
+	assert_eq!(4, mat.dims());
+	assert_eq!(arr.into_dyn(), mat.as_array_view_nd::<i32>()?);
+
+	let arr = Array3::from_shape_fn((2, 2, 3), |(_, _, ch)| ch as u8);
+	let mat = Mat::from_array_channels(&arr)?;
+	assert_eq!(Vec3b::typ(), mat.typ()?);
+	assert_eq!(Vec3b::from([0, 1, 2]), *mat.at_2d::<Vec3b>(1, 1)?);
+	assert_eq!(arr, mat.as_array_view_channels::<u8>()?);
+	Ok(())
+}
+
+#[test]
+fn vector_array_view() -> Result<()> {
+	let mut vec = Vector::<i32>::from_iter(vec![1, 2, 3]);
+	assert_eq!(6, vec.as_array_view().sum());
+	vec.as_array_view_mut().map_inplace(|x| *x *= 2);
+	assert_eq!(vec![2, 4, 6], vec.to_vec());
+	Ok(())
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "rayon")]
+
+use matches::assert_matches;
+use rayon::prelude::*;
+
+use opencv::{
+	core::{self, Rect, Scalar},
+	Error,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn mat_par_rows_mut() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(100, 50, i32::typ(), Scalar::default())?;
+	mat.par_rows_mut::<i32>()?
+		.enumerate()
+		.for_each(|(row_n, row)| row.iter_mut().enumerate().for_each(|(col_n, x)| *x = (row_n * 100 + col_n) as i32));
+	assert_eq!(0, *mat.at_2d::<i32>(0, 0)?);
+	assert_eq!(4249, *mat.at_2d::<i32>(42, 49)?);
+	assert_eq!(9949, *mat.at_2d::<i32>(99, 49)?);
+
+	{
+		let mut roi = mat.roi_mut(Rect::new(10, 10, 5, 5))?;
+		assert_eq!(5, roi.par_rows_mut::<i32>()?.len());
+		roi.par_rows_mut::<i32>()?.for_each(|row| row.iter_mut().for_each(|x| *x = -1));
+	}
+	assert_eq!(909, *mat.at_2d::<i32>(9, 9)?);
+	assert_eq!(-1, *mat.at_2d::<i32>(10, 10)?);
+	assert_eq!(-1, *mat.at_2d::<i32>(14, 14)?);
+	assert_eq!(1415, *mat.at_2d::<i32>(14, 15)?);
+
+	assert_matches!(mat.par_rows_mut::<u8>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
+
+#[test]
+fn mat_par_chunks_mut() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(10, 3, u16::typ(), Scalar::default())?;
+	let chunks = mat.par_chunks_mut::<u16>(4)?;
+	assert_eq!(3, chunks.len());
+	let chunk_lens = chunks
+		.enumerate()
+		.map(|(chunk_n, chunk)| {
+			let len = chunk.len();
+			chunk.for_each(|row| row.iter_mut().for_each(|x| *x = chunk_n as u16 + 1));
+			len
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(vec![4, 4, 2], chunk_lens);
+	assert_eq!(1, *mat.at_2d::<u16>(3, 2)?);
+	assert_eq!(2, *mat.at_2d::<u16>(4, 0)?);
+	assert_eq!(3, *mat.at_2d::<u16>(9, 1)?);
+
+	let rev_lens = mat.par_chunks_mut::<u16>(4)?.rev().map(|chunk| chunk.len()).collect::<Vec<_>>();
+	assert_eq!(vec![2, 4, 4], rev_lens);
+
+	assert_matches!(mat.par_chunks_mut::<u16>(0), Err(Error { code: core::StsBadArg, .. }));
+	Ok(())
+}
@@ -1,7 +1,9 @@
+use std::mem;
+
 use matches::assert_matches;
 
 use opencv::{
-	core::{self, Matx22d, Matx23f, Matx32f, Matx33d, Matx66f, Point2f, Scalar},
+	core::{self, Matx, Matx22d, Matx22f, Matx23f, Matx31d, Matx32f, Matx33d, Matx33f, Matx66f, Point2f, Scalar, ValidMatxType, Vec2d, Vec2f, Vec3d, Vec3f, VecN},
 	imgproc,
 	prelude::*,
 	Result,
@@ -101,3 +103,84 @@ fn matx_all() -> Result<()> {
 	assert_eq!(mat[(3, 4)], 81.);
 	Ok(())
 }
+
+#[test]
+fn matx_ops() {
+	let a = Matx22d::from([
+		[1., 2.],
+		[3., 4.],
+	]);
+	let b = Matx22d::eye() * 2.;
+	assert_eq!(Matx22d::from([[3., 2.], [3., 6.]]), a + b);
+	assert_eq!(Matx22d::from([[-1., 2.], [3., 2.]]), a - b);
+	assert_eq!(Matx22d::from([[-1., -2.], [-3., -4.]]), -a);
+	assert_eq!(Matx22d::from([[0.5, 1.], [1.5, 2.]]), a / 2.);
+	assert_eq!(Matx22d::from([[2., 0.], [0., 8.]]), a.mul_elem(b));
+
+	let m = Matx23f::from([
+		[1., 2., 3.],
+		[4., 5., 6.],
+	]);
+	let mt = m.t();
+	assert_eq!(&[1., 4., 2., 5., 3., 6.], mt.val());
+	assert_eq!(Matx22f::from([[14., 32.], [32., 77.]]), m * mt);
+	assert_eq!(Matx33f::from([[17., 22., 27.], [22., 29., 36.], [27., 36., 45.]]), mt * m);
+	assert_eq!(Vec2f::from([14., 32.]), m * Vec3f::from([1., 2., 3.]));
+}
+
+#[test]
+fn matx_linalg() {
+	let a = Matx33d::from([
+		[2., 0., 1.],
+		[1., 3., 2.],
+		[1., 1., 2.],
+	]);
+	assert!((a.det() - 6.).abs() < 1e-12);
+	let inv = a.inv().unwrap();
+	let eye = a * inv;
+	for (x, y) in eye.val().iter().zip(Matx33d::eye().val()) {
+		assert!((x - y).abs() < 1e-12);
+	}
+	let x = a.solve_vec(Vec3d::from([3., 6., 4.])).unwrap();
+	for (x, y) in x.iter().zip(&[1., 1., 1.]) {
+		assert!((x - y).abs() < 1e-12);
+	}
+	let x = a.solve(Matx31d::from([[3.], [6.], [4.]])).unwrap();
+	assert!((x.val()[2] - 1.).abs() < 1e-12);
+
+	let singular = Matx33d::from([
+		[1., 2., 3.],
+		[2., 4., 6.],
+		[1., 1., 1.],
+	]);
+	assert_eq!(0., singular.det());
+	assert!(singular.inv().is_none());
+	assert!(singular.solve_vec(Vec3d::all(1.)).is_none());
+
+	let nan = Matx22d::from([[f64::NAN, 1.], [1., 2.]]);
+	assert!(nan.det().is_nan());
+	assert!(nan.inv().is_none());
+	assert!(nan.solve_vec(Vec2d::all(1.)).is_none());
+
+	assert_eq!(Vec3d::from([2., 3., 2.]), a.diag());
+	assert_eq!(Matx22d::from([[5., 0.], [0., 7.]]), Matx22d::from_diag(Vec2d::from([5., 7.])));
+}
+
+#[test]
+fn matx_generic() -> Result<()> {
+	fn sum<T: ValidMatxType, const R: usize, const C: usize>(m: &Matx<T, R, C>) -> T {
+		m.val().iter().fold(T::default(), |acc, &x| acc + x)
+	}
+
+	assert_eq!(mem::size_of::<f64>() * 9, mem::size_of::<Matx33d>());
+	let m = Matx::<i32, 2, 5>::from([
+		[1, 2, 3, 4, 5],
+		[6, 7, 8, 9, 10],
+	]);
+	assert_eq!(55, sum(&m));
+	assert_eq!(8, m[(1, 2)]);
+	assert_eq!(Matx::<i32, 5, 2>::from_slice(&[1, 6, 2, 7, 3, 8, 4, 9, 5, 10])?, m.t());
+	assert_eq!(VecN::from([15, 40]), m * VecN::<i32, 5>::all(1));
+	assert!(Matx::<u8, 2, 2>::from_slice(&[1, 2, 3]).is_err());
+	Ok(())
+}
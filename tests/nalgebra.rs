@@ -0,0 +1,65 @@
+#![cfg(feature = "nalgebra")]
+
+use std::convert::TryFrom;
+
+use matches::assert_matches;
+use nalgebra::{Isometry3, Matrix2x3, Matrix3, Point2, Point3, Translation3, UnitQuaternion, Vector3};
+
+use opencv::{
+	core::{self, Affine3d, Matx23f, Matx33d, Point2i, Point3d, Vec3d},
+	Error,
+	prelude::*,
+};
+
+#[test]
+fn nalgebra_matx() {
+	let mut matx = Matx33d::eye();
+	matx[(0, 1)] = 2.;
+	matx[(2, 0)] = 3.;
+	let m = Matrix3::from(matx);
+	assert_eq!(2., m[(0, 1)]);
+	assert_eq!(3., m[(2, 0)]);
+	assert_eq!(1., m[(1, 1)]);
+	assert_eq!(matx, Matx33d::from(m));
+
+	let m = Matrix2x3::new(1f32, 2., 3., 4., 5., 6.);
+	let matx = Matx23f::from(m);
+	assert_eq!(&[1., 2., 3., 4., 5., 6.], matx.val());
+	assert_eq!(m, matx.into());
+}
+
+#[test]
+fn nalgebra_vec_point() {
+	let v = Vec3d::from([1., 2., 3.]);
+	let nv: Vector3<f64> = v.into();
+	assert_eq!(Vector3::new(1., 2., 3.), nv);
+	assert_eq!(v, Vec3d::from(nv));
+
+	let pt = Point3d::new(1., 2., 3.);
+	let npt: Point3<f64> = pt.into();
+	assert_eq!(Point3::new(1., 2., 3.), npt);
+	assert_eq!(pt, Point3d::from(npt));
+
+	let pt = Point2i::new(-1, 5);
+	assert_eq!(Point2::new(-1, 5), Point2::from(pt));
+	assert_eq!(pt, Point2i::from(Point2::new(-1, 5)));
+}
+
+#[test]
+fn nalgebra_affine3() {
+	let iso = Isometry3::from_parts(Translation3::new(1., 2., 3.), UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3));
+	let affine = Affine3d::from(iso);
+	assert_eq!(3., affine.matrix[(2, 3)]);
+	assert_eq!(1., affine.matrix[(3, 3)]);
+	let back = Isometry3::try_from(affine).unwrap();
+	assert!((back.translation.vector - iso.translation.vector).norm() < 1e-12);
+	assert!(back.rotation.angle_to(&iso.rotation) < 1e-12);
+
+	let na_affine = nalgebra::Affine3::from(affine);
+	assert_eq!(iso.to_homogeneous(), na_affine.to_homogeneous());
+	assert_eq!(affine.matrix, Affine3d::from(na_affine).matrix);
+
+	let mut scaled = Affine3d::default();
+	scaled.matrix[(0, 0)] = 2.;
+	assert_matches!(Isometry3::try_from(scaled), Err(Error { code: core::StsBadArg, .. }));
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "serde")]
+
+use opencv::{
+	core::{self, DMatch, KeyPoint, Mat, Matx23f, Point2f, Point3d, Rect, RotatedRect, Scalar, Size2f, TermCriteria, Vec3b, Vec3d, Vector},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn serde_value_types() -> Result<()> {
+	let pt = Point3d::new(1., 2., 3.);
+	assert_eq!(r#"{"x":1.0,"y":2.0,"z":3.0}"#, serde_json::to_string(&pt).unwrap());
+	assert_eq!(pt, serde_json::from_str(r#"{"x":1.0,"y":2.0,"z":3.0}"#).unwrap());
+
+	let rect = Rect::new(1, 2, 3, 4);
+	let json = serde_json::to_string(&rect).unwrap();
+	assert_eq!(r#"{"x":1,"y":2,"width":3,"height":4}"#, json);
+	assert_eq!(rect, serde_json::from_str(&json).unwrap());
+
+	let v = Vec3d::from([1., 2., 3.]);
+	assert_eq!("[1.0,2.0,3.0]", serde_json::to_string(&v).unwrap());
+	assert_eq!(v, serde_json::from_str("[1.0,2.0,3.0]").unwrap());
+	assert!(serde_json::from_str::<Vec3d>("[1.0,2.0]").is_err());
+
+	let m = Matx23f::from([
+		[1., 2., 3.],
+		[4., 5., 6.],
+	]);
+	let json = serde_json::to_string(&m).unwrap();
+	assert_eq!("[[1.0,2.0,3.0],[4.0,5.0,6.0]]", json);
+	assert_eq!(m, serde_json::from_str(&json).unwrap());
+
+	let s = Scalar::new(1., 2., 3., 4.);
+	assert_eq!(s, serde_json::from_str(&serde_json::to_string(&s).unwrap()).unwrap());
+
+	let kp = KeyPoint { pt: Point2f::new(10., 20.), size: 3., angle: 90., response: 0.5, octave: 1, class_id: -1 };
+	let json = serde_json::to_string(&kp).unwrap();
+	assert_eq!(r#"{"pt":{"x":10.0,"y":20.0},"size":3.0,"angle":90.0,"response":0.5,"octave":1,"class_id":-1}"#, json);
+	assert_eq!(kp, serde_json::from_str(&json).unwrap());
+
+	let m = DMatch { query_idx: 1, train_idx: 2, img_idx: 0, distance: 0.25 };
+	assert_eq!(m, serde_json::from_str(&serde_json::to_string(&m).unwrap()).unwrap());
+
+	let crit = TermCriteria { typ: core::TermCriteria_Type::COUNT as i32, max_count: 30, epsilon: 0.01 };
+	assert_eq!(crit, serde_json::from_str(&serde_json::to_string(&crit).unwrap()).unwrap());
+
+	let rect = RotatedRect::new(Point2f::new(1., 2.), Size2f::new(3., 4.), 45.)?;
+	let json = serde_json::to_string(&rect).unwrap();
+	assert_eq!(r#"{"center":{"x":1.0,"y":2.0},"size":{"width":3.0,"height":4.0},"angle":45.0}"#, json);
+	let rect: RotatedRect = serde_json::from_str(&json).unwrap();
+	assert_eq!(Point2f::new(1., 2.), rect.center());
+	assert_eq!(45., rect.angle());
+	Ok(())
+}
+
+#[test]
+fn serde_vector() -> Result<()> {
+	let v = Vector::<i32>::from_iter(vec![1, 2, 3]);
+	assert_eq!("[1,2,3]", serde_json::to_string(&v).unwrap());
+	let v: Vector<i32> = serde_json::from_str("[4,5]").unwrap();
+	assert_eq!(vec![4, 5], v.to_vec());
+
+	let v: Vector<String> = serde_json::from_str(r#"["a","bc"]"#).unwrap();
+	assert_eq!(vec!["a".to_string(), "bc".to_string()], v.to_vec());
+	// escaped strings can't be borrowed from the input
+	let v: Vector<String> = serde_json::from_str(r#"["a\"b","\u00e9"]"#).unwrap();
+	assert_eq!(vec!["a\"b".to_string(), "é".to_string()], v.to_vec());
+	let v: Vector<String> = serde_json::from_reader(&br#"["x","yz"]"#[..]).unwrap();
+	assert_eq!(vec!["x".to_string(), "yz".to_string()], v.to_vec());
+
+	let kps = Vector::<KeyPoint>::from_iter(vec![KeyPoint::default()?; 2]);
+	let back: Vector<KeyPoint> = serde_json::from_str(&serde_json::to_string(&kps).unwrap()).unwrap();
+	assert_eq!(kps.to_vec(), back.to_vec());
+	Ok(())
+}
+
+#[test]
+fn serde_mat() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[
+		[1u8, 2, 3],
+		[4, 5, 6],
+	])?;
+	let json = serde_json::to_string(&mat).unwrap();
+	assert_eq!(r#"{"rows":2,"cols":3,"type":0,"data":[1,2,3,4,5,6]}"#, json);
+	let back: Mat = serde_json::from_str(&json).unwrap();
+	assert_eq!(mat.data_typed::<u8>()?, back.data_typed::<u8>()?);
+
+	let mat = Mat::new_rows_cols_with_default(3, 4, Vec3b::typ(), Scalar::new(1., 2., 3., 0.))?;
+	let bin = bincode::serialize(&mat).unwrap();
+	// 3 i32 fields, u64 length prefix and the raw data
+	assert_eq!(3 * 4 + 8 + 3 * 4 * 3, bin.len());
+	let back: Mat = bincode::deserialize(&bin).unwrap();
+	assert_eq!(mat.typ()?, back.typ()?);
+	assert_eq!(mat.data_typed::<Vec3b>()?, back.data_typed::<Vec3b>()?);
+
+	// non-continuous
+	let roi = Mat::roi(&mat, core::Rect::new(1, 1, 2, 2))?;
+	let back: Mat = bincode::deserialize(&bincode::serialize(&roi).unwrap()).unwrap();
+	assert_eq!(2, back.rows());
+	assert_eq!(Vec3b::from([1, 2, 3]), *back.at_2d::<Vec3b>(1, 1)?);
+
+	let back: Mat = serde_json::from_str(&serde_json::to_string(&Mat::default()?).unwrap()).unwrap();
+	assert!(back.empty()?);
+
+	assert!(serde_json::from_str::<Mat>(r#"{"rows":2,"cols":3,"type":0,"data":[1,2,3]}"#).is_err());
+	// the size is validated before the allocation
+	assert!(serde_json::from_str::<Mat>(r#"{"rows":2000000000,"cols":2000000000,"type":0,"data":[]}"#).is_err());
+	assert!(serde_json::from_str::<Mat>(r#"{"rows":1,"cols":1,"type":-1,"data":[1]}"#).is_err());
+	assert!(serde_json::from_str::<Mat>(r#"{"rows":1,"cols":1,"type":4096,"data":[1]}"#).is_err());
+	Ok(())
+}
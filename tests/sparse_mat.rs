@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+
+use opencv::{
+	core::{self, SparseMat, SparseMat_, Vec2f},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn sparse_mat_access() -> Result<()> {
+	let mut mat = SparseMat_::<f32>::new(&[10, 20, 30])?;
+	assert_eq!(&[10, 20, 30], mat.sizes()?);
+	assert!(mat.is_empty()?);
+	assert_eq!(None, mat.get(&[1, 2, 3]));
+
+	assert_eq!(None, mat.insert(&[1, 2, 3], 1.5)?);
+	assert_eq!(None, mat.insert(&[9, 19, 29], -2.)?);
+	assert_eq!(Some(1.5), mat.insert(&[1, 2, 3], 2.5)?);
+	assert_eq!(2, mat.len()?);
+	assert_eq!(Some(&2.5), mat.get(&[1, 2, 3]));
+	assert_eq!(None, mat.get(&[1, 2, 4]));
+	assert_eq!(2, mat.len()?, "get() must not create missing elements");
+
+	*mat.get_mut(&[9, 19, 29]).expect("Missing element") *= 2.;
+	assert_eq!(Some(&-4.), mat.get(&[9, 19, 29]));
+
+	assert!(mat.insert(&[10, 0, 0], 1.).is_err());
+	assert!(mat.insert(&[1, 2], 1.).is_err());
+	assert_eq!(None, mat.get(&[-1, 0, 0]));
+
+	assert_eq!(Some(2.5), mat.remove(&[1, 2, 3])?);
+	assert_eq!(None, mat.remove(&[1, 2, 3])?);
+	assert_eq!(1, mat.len()?);
+	Ok(())
+}
+
+#[test]
+fn sparse_mat_iter() -> Result<()> {
+	let nodes = vec![(vec![0, 1], 1u16), (vec![3, 3], 2), (vec![2, 0], 3)];
+	let mat = SparseMat_::from_nodes(&[4, 4], nodes.iter().cloned())?;
+	let iter = mat.iter()?;
+	assert_eq!(3, iter.len());
+	let mut res = iter.map(|(idx, &val)| (idx.to_vec(), val)).collect::<Vec<_>>();
+	res.sort_unstable();
+	let mut expected = nodes;
+	expected.sort_unstable();
+	assert_eq!(expected, res);
+	assert_eq!(6, (&mat).into_iter().map(|(_, &val)| val).sum::<u16>());
+	Ok(())
+}
+
+#[test]
+fn sparse_mat_dense() -> Result<()> {
+	let dense = Mat::from_slice_2d(&[[0f32, 1.], [0., 0.], [2., 0.]])?;
+	let mat = SparseMat_::<f32>::from_mat(&dense)?;
+	assert_eq!(&[3, 2], mat.sizes()?);
+	assert_eq!(2, mat.len()?);
+	assert_eq!(Some(&2.), mat.get(&[2, 0]));
+	assert!(SparseMat_::<f64>::from_mat(&dense).is_err());
+
+	let back = mat.to_mat()?;
+	assert_eq!(dense.data_typed::<f32>()?, back.data_typed::<f32>()?);
+
+	let untyped = SparseMat::from(mat);
+	assert!(SparseMat_::<i32>::try_from(untyped.clone()).is_err());
+	let mat = SparseMat_::<f32>::try_from(untyped)?;
+	assert_eq!(core::CV_32F, mat.typ()?);
+
+	let mat = SparseMat_::from_nodes(&[2, 2], vec![([1, 1], Vec2f::from([1., 2.]))])?;
+	assert_eq!(Vec2f::from([1., 2.]), *mat.to_mat()?.at_2d::<Vec2f>(1, 1)?);
+	Ok(())
+}
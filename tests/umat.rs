@@ -126,3 +126,25 @@ fn umat_continuous() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn umat_map() -> Result<()> {
+    let mat = Mat::from_slice_2d(&[[1u8, 2, 3], [4, 5, 6]])?;
+    let mut umat = UMat::from_mat(&mat, UMatUsageFlags::USAGE_DEFAULT)?;
+    assert_eq!(Size::new(3, 2), umat.size()?);
+    assert_eq!(u8::typ(), umat.typ()?);
+    {
+        let mapped = umat.map_read()?;
+        assert_eq!(Size::new(3, 2), mapped.size()?);
+        assert_eq!(5, *mapped.at_2d::<u8>(1, 1)?);
+    }
+    {
+        let mut mapped = umat.map_write()?;
+        *mapped.at_2d_mut::<u8>(0, 2)? = 30;
+        mapped.at_row_mut::<u8>(1)?.copy_from_slice(&[40, 50, 60]);
+    }
+    let copy = umat.to_mat()?;
+    assert_eq!(vec![vec![1, 2, 30], vec![40, 50, 60]], copy.to_vec_2d::<u8>()?);
+    assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], mat.to_vec_2d::<u8>()?);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use opencv::core::Vec3b;
+use opencv::core::{Vec3b, Vec3d};
 
 #[test]
 fn vec() {
@@ -14,3 +14,17 @@ fn vec() {
 fn vec_deref() {
     assert_eq!(vec![10, 20, 30], Vec3b::from([10, 20, 30]).to_vec());
 }
+
+#[test]
+fn vec_ops() {
+    let a = Vec3d::from([1., 2., 3.]);
+    let b = Vec3d::from([4., 5., 6.]);
+    assert_eq!(Vec3d::from([5., 7., 9.]), a + b);
+    assert_eq!(Vec3d::from([-3., -3., -3.]), a - b);
+    assert_eq!(Vec3d::from([2., 4., 6.]), a * 2.);
+    assert_eq!(Vec3d::from([0.5, 1., 1.5]), a / 2.);
+    assert_eq!(Vec3d::from([-1., -2., -3.]), -a);
+    assert_eq!(32., a.dot(&b));
+    assert_eq!(Vec3d::from([-3., 6., -3.]), a.cross(&b));
+    assert_eq!(Vec3b::from([2, 4, 6]), Vec3b::from([1, 2, 3]) * 2);
+}